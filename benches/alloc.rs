@@ -0,0 +1,142 @@
+//! Per-operation benchmarks for the arena's hot paths: small allocations,
+//! slice copies, growing collections, and resetting.
+//!
+//! Every benchmark sets its group's throughput to the number of operations in
+//! one iteration, so criterion reports time per operation.
+
+use bumpalo::{collections::String, collections::Vec, Bump};
+use criterion::*;
+
+const OPS: usize = 10_000;
+
+fn bench_alloc_small(c: &mut Criterion) {
+    let mut group = c.benchmark_group("alloc-small");
+    group.throughput(Throughput::Elements(OPS as u64));
+    group.bench_function("u64", |b| {
+        let mut bump = Bump::with_capacity(OPS * std::mem::size_of::<u64>());
+        b.iter(|| {
+            bump.reset();
+            for i in 0..OPS {
+                black_box(bump.alloc(black_box(i as u64)));
+            }
+        });
+    });
+}
+
+fn bench_alloc_slice_copy(c: &mut Criterion) {
+    let mut group = c.benchmark_group("alloc-slice-copy");
+    for &len in &[1, 16, 256, 4096] {
+        let src = vec![0u8; len];
+        // Enough copies of the slice for one iteration to stay in one chunk.
+        let copies = (1 << 20) / len;
+        group.throughput(Throughput::Elements(copies as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(len), &src, |b, src| {
+            let mut bump = Bump::with_capacity(copies * len);
+            b.iter(|| {
+                bump.reset();
+                for _ in 0..copies {
+                    black_box(bump.alloc_slice_copy(black_box(&src[..])));
+                }
+            });
+        });
+    }
+}
+
+fn bench_vec_push(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vec-push");
+    group.throughput(Throughput::Elements(OPS as u64));
+    group.bench_function("growing", |b| {
+        let mut bump = Bump::new();
+        b.iter(|| {
+            bump.reset();
+            let mut v = Vec::new_in(&bump);
+            for i in 0..OPS {
+                v.push(black_box(i));
+            }
+            black_box(v);
+        });
+    });
+    group.bench_function("with-capacity", |b| {
+        let mut bump = Bump::new();
+        b.iter(|| {
+            bump.reset();
+            let mut v = Vec::with_capacity_in(OPS, &bump);
+            for i in 0..OPS {
+                v.push(black_box(i));
+            }
+            black_box(v);
+        });
+    });
+}
+
+fn bench_string_build(c: &mut Criterion) {
+    let mut group = c.benchmark_group("string-build");
+    group.throughput(Throughput::Elements(OPS as u64));
+    group.bench_function("push-str", |b| {
+        let mut bump = Bump::new();
+        b.iter(|| {
+            bump.reset();
+            let mut s = String::new_in(&bump);
+            for _ in 0..OPS {
+                s.push_str(black_box("hello"));
+            }
+            black_box(s);
+        });
+    });
+    group.bench_function("push", |b| {
+        let mut bump = Bump::new();
+        b.iter(|| {
+            bump.reset();
+            let mut s = String::new_in(&bump);
+            for _ in 0..OPS {
+                s.push(black_box('x'));
+            }
+            black_box(s);
+        });
+    });
+}
+
+fn bench_reset(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reset");
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("clean", |b| {
+        let mut bump = Bump::new();
+        b.iter(|| black_box(&mut bump).reset());
+    });
+    group.bench_function("one-chunk", |b| {
+        let mut bump = Bump::with_capacity(4096);
+        b.iter(|| {
+            bump.alloc(black_box(1u64));
+            bump.reset();
+        });
+    });
+    for &chunks in &[2, 4, 8] {
+        group.bench_with_input(
+            BenchmarkId::new("many-chunks", chunks),
+            &chunks,
+            |b, &chunks| {
+                b.iter_batched_ref(
+                    || {
+                        let bump = Bump::new();
+                        while bump.used_chunks() < chunks {
+                            bump.alloc([0u8; 256]);
+                        }
+                        bump
+                    },
+                    |bump| bump.reset(),
+                    BatchSize::SmallInput,
+                );
+            },
+        );
+    }
+}
+
+criterion_group!(
+    benches,
+    bench_alloc_small,
+    bench_alloc_slice_copy,
+    bench_vec_push,
+    bench_string_build,
+    bench_reset
+);
+criterion_main!(benches);
@@ -0,0 +1,58 @@
+use core::fmt;
+use core::ops::Deref;
+
+/// A value that is known to point to memory aligned to at least `A` bytes.
+///
+/// This is created by
+/// [`Bump::alloc_aligned_slice`](./struct.Bump.html#method.alloc_aligned_slice),
+/// which wraps the slice it allocates so that the alignment travels with it
+/// in the type. Code that receives an `Aligned<&mut [T], A>` can rely on
+/// `as_ptr()` being a multiple of `A` without checking, for example to use
+/// aligned SIMD loads and stores.
+///
+/// `Aligned` only dereferences to a shared reference to the value it wraps,
+/// so the value cannot be replaced by one pointing somewhere else. Use
+/// [`as_mut_slice`](#method.as_mut_slice) to modify the elements of a
+/// wrapped slice.
+pub struct Aligned<T, const A: usize>(T);
+
+impl<T, const A: usize> Aligned<T, A> {
+    /// The alignment, in bytes, that the wrapped value is known to have.
+    pub const ALIGN: usize = A;
+
+    /// Wraps `value` without checking its alignment.
+    ///
+    /// ## Safety
+    ///
+    /// `value` must point to memory aligned to at least `A` bytes, and `A`
+    /// must be a power of two.
+    pub unsafe fn new_unchecked(value: T) -> Aligned<T, A> {
+        Aligned(value)
+    }
+
+    /// Unwraps the value, forgetting its known alignment.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T, const A: usize> Aligned<&mut [T], A> {
+    /// Returns the wrapped slice's elements for modification.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.0
+    }
+}
+
+impl<T, const A: usize> Deref for Aligned<T, A> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: fmt::Debug, const A: usize> fmt::Debug for Aligned<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Aligned").field(&self.0).finish()
+    }
+}
@@ -139,6 +139,9 @@ use {
     },
 };
 
+#[cfg(feature = "coerce_unsized")]
+use core::{marker::Unsize, ops::CoerceUnsized};
+
 /// An owned pointer to a bump-allocated `T` value, that runs `Drop`
 /// implementations.
 ///
@@ -639,6 +642,9 @@ impl<'a, T: ?Sized> AsMut<T> for Box<'a, T> {
 
 impl<'a, T: ?Sized> Unpin for Box<'a, T> {}
 
+#[cfg(feature = "coerce_unsized")]
+impl<'a, T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<Box<'a, U>> for Box<'a, T> {}
+
 impl<'a, F: ?Sized + Future + Unpin> Future for Box<'a, F> {
     type Output = F::Output;
 
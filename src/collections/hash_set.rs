@@ -0,0 +1,491 @@
+//! A hash set whose storage is allocated inside a `Bump` arena.
+//!
+//! # Examples
+//!
+//! ```
+//! use bumpalo::{Bump, collections::HashSet};
+//! use std::collections::hash_map::RandomState;
+//!
+//! let b = Bump::new();
+//! let mut visited: HashSet<u32, RandomState> = HashSet::new_in(&b);
+//!
+//! assert!(visited.insert(1));
+//! assert!(visited.insert(2));
+//! assert!(!visited.insert(1));
+//!
+//! assert!(visited.contains(&1));
+//! assert!(!visited.contains(&3));
+//! assert_eq!(visited.len(), 2);
+//! ```
+
+use crate::collections::Vec;
+use crate::Bump;
+use core::borrow::Borrow;
+use core::fmt;
+use core::hash::{BuildHasher, Hash, Hasher};
+use core::iter::FusedIterator;
+use core::mem;
+use core::slice;
+
+enum Slot<T> {
+    Empty,
+    Deleted,
+    Full(T),
+}
+
+/// A hash set backed by a `Bump` arena.
+///
+/// The set uses open addressing with linear probing, and its table is a
+/// [`Vec`] in the arena. Growing the set moves its elements to a new, larger
+/// table and leaves the old one behind in the arena until it is reset; the
+/// set never shrinks.
+///
+/// Like the other collections in this module, dropping a `HashSet` drops its
+/// elements, and only the memory of its table stays behind in the arena.
+/// Elements replaced or removed through the set's methods are dropped or
+/// returned as usual.
+///
+/// There is no default hasher in `core`, so the `BuildHasher` is always
+/// chosen explicitly, for example `std::collections::hash_map::RandomState`.
+///
+/// [`Vec`]: ../vec/struct.Vec.html
+///
+/// # Examples
+///
+/// ```
+/// use bumpalo::{Bump, collections::HashSet};
+/// use std::collections::hash_map::RandomState;
+///
+/// let b = Bump::new();
+/// let mut set = HashSet::with_hasher_in(RandomState::new(), &b);
+///
+/// set.insert("a");
+/// set.insert("b");
+/// assert!(set.remove("a"));
+/// assert!(!set.remove("a"));
+///
+/// let items: Vec<_> = set.iter().collect();
+/// assert_eq!(items, [&"b"]);
+/// ```
+pub struct HashSet<'bump, T, S> {
+    table: Vec<'bump, Slot<T>>,
+    len: usize,
+    deleted: usize,
+    hash_builder: S,
+    bump: &'bump Bump,
+}
+
+impl<'bump, T, S: Default> HashSet<'bump, T, S> {
+    /// Constructs a new, empty `HashSet` in the given arena, with a default
+    /// hasher.
+    ///
+    /// The set does not allocate until elements are inserted into it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::HashSet};
+    /// use std::collections::hash_map::RandomState;
+    ///
+    /// let b = Bump::new();
+    /// let set: HashSet<i32, RandomState> = HashSet::new_in(&b);
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn new_in(bump: &'bump Bump) -> HashSet<'bump, T, S> {
+        HashSet::with_hasher_in(S::default(), bump)
+    }
+
+    /// Constructs a new, empty `HashSet` in the given arena that can hold at
+    /// least `capacity` elements without growing, with a default hasher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::HashSet};
+    /// use std::collections::hash_map::RandomState;
+    ///
+    /// let b = Bump::new();
+    /// let set: HashSet<i32, RandomState> = HashSet::with_capacity_in(10, &b);
+    /// assert!(set.capacity() >= 10);
+    /// ```
+    pub fn with_capacity_in(capacity: usize, bump: &'bump Bump) -> HashSet<'bump, T, S> {
+        HashSet::with_capacity_and_hasher_in(capacity, S::default(), bump)
+    }
+}
+
+impl<'bump, T, S> HashSet<'bump, T, S> {
+    /// Constructs a new, empty `HashSet` in the given arena, which will use
+    /// the given hash builder to hash its elements.
+    ///
+    /// The set does not allocate until elements are inserted into it.
+    pub fn with_hasher_in(hash_builder: S, bump: &'bump Bump) -> HashSet<'bump, T, S> {
+        HashSet {
+            table: Vec::new_in(bump),
+            len: 0,
+            deleted: 0,
+            hash_builder,
+            bump,
+        }
+    }
+
+    /// Constructs a new, empty `HashSet` in the given arena that can hold at
+    /// least `capacity` elements without growing, and which will use the given
+    /// hash builder to hash its elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table size overflows `usize`.
+    pub fn with_capacity_and_hasher_in(
+        capacity: usize,
+        hash_builder: S,
+        bump: &'bump Bump,
+    ) -> HashSet<'bump, T, S> {
+        HashSet {
+            table: new_table(table_size_for(capacity), bump),
+            len: 0,
+            deleted: 0,
+            hash_builder,
+            bump,
+        }
+    }
+
+    /// Returns the number of elements in the set.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the set contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of elements the set can hold without growing.
+    pub fn capacity(&self) -> usize {
+        max_load(self.table.len())
+    }
+
+    /// Returns a reference to the set's `BuildHasher`.
+    pub fn hasher(&self) -> &S {
+        &self.hash_builder
+    }
+
+    /// Returns the `Bump` arena that this set allocates its table in.
+    pub fn bump(&self) -> &'bump Bump {
+        self.bump
+    }
+
+    /// An iterator visiting all elements in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::HashSet};
+    /// use std::collections::hash_map::RandomState;
+    ///
+    /// let b = Bump::new();
+    /// let mut set: HashSet<_, RandomState> = HashSet::new_in(&b);
+    /// set.insert(1);
+    /// set.insert(2);
+    ///
+    /// let mut items: Vec<_> = set.iter().cloned().collect();
+    /// items.sort();
+    /// assert_eq!(items, [1, 2]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            slots: self.table.iter(),
+            remaining: self.len,
+        }
+    }
+
+    /// Removes all elements from the set, dropping them, but keeps its
+    /// allocated table for reuse.
+    pub fn clear(&mut self) {
+        for slot in self.table.iter_mut() {
+            *slot = Slot::Empty;
+        }
+        self.len = 0;
+        self.deleted = 0;
+    }
+}
+
+impl<'bump, T, S> HashSet<'bump, T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    /// Adds a value to the set.
+    ///
+    /// Returns `true` if the set did not already contain an equal value. If it
+    /// did, the set is not modified and `value` is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if growing the table overflows `usize`.
+    pub fn insert(&mut self, value: T) -> bool {
+        let hash = self.hash(&value);
+        let slot = if self.table.is_empty() {
+            None
+        } else {
+            match self.probe(hash, &value) {
+                Ok(_) => return false,
+                Err(index) => Some(index),
+            }
+        };
+
+        let index = match slot {
+            // Reusing a deleted slot leaves the load unchanged.
+            Some(index) if matches!(self.table[index], Slot::Deleted) => {
+                self.deleted -= 1;
+                index
+            }
+            Some(index) if self.len + self.deleted < self.capacity() => index,
+            // Growing the table moves everything, so look for a slot again.
+            _ => {
+                self.reserve(1);
+                self.find_insert_slot(hash)
+            }
+        };
+        self.table[index] = Slot::Full(value);
+        self.len += 1;
+        true
+    }
+
+    /// Returns `true` if the set contains a value equal to `value`.
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.find(value).is_some()
+    }
+
+    /// Returns a reference to the value in the set, if any, that is equal to
+    /// the given value.
+    pub fn get<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        let index = self.find(value)?;
+        match &self.table[index] {
+            Slot::Full(t) => Some(t),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Removes a value equal to `value` from the set, dropping it. Returns
+    /// whether such a value was present in the set.
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        self.take(value).is_some()
+    }
+
+    /// Removes and returns the value in the set, if any, that is equal to the
+    /// given value.
+    pub fn take<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        let index = self.find(value)?;
+        self.len -= 1;
+        self.deleted += 1;
+        match mem::replace(&mut self.table[index], Slot::Deleted) {
+            Slot::Full(t) => Some(t),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be
+    /// inserted without growing the table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new table size overflows `usize`.
+    pub fn reserve(&mut self, additional: usize) {
+        let needed = self.len.checked_add(additional).expect("capacity overflow");
+        // Deleted slots still lengthen probe sequences, so count them against
+        // the load factor until the next rehash clears them out.
+        if needed + self.deleted <= self.capacity() {
+            return;
+        }
+
+        // If the live elements alone fit, rehash at the same size just to
+        // clear out the deleted slots.
+        let new_size = if needed <= self.capacity() {
+            self.table.len()
+        } else {
+            table_size_for(needed.max(self.capacity() + 1))
+        };
+        let old = mem::replace(&mut self.table, new_table(new_size, self.bump));
+        self.deleted = 0;
+        for slot in old {
+            if let Slot::Full(t) = slot {
+                let index = self.find_insert_slot(self.hash(&t));
+                self.table[index] = Slot::Full(t);
+            }
+        }
+    }
+
+    // `BuildHasher::hash_one` is newer than our minimum supported Rust.
+    #[allow(clippy::manual_hash_one)]
+    fn hash<Q: ?Sized + Hash>(&self, value: &Q) -> u64 {
+        let mut hasher = self.hash_builder.build_hasher();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn find<Q>(&self, value: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Eq + Hash,
+    {
+        if self.len == 0 {
+            return None;
+        }
+        self.probe(self.hash(value), value).ok()
+    }
+
+    // Returns `Ok` with the index of the value equal to `value`, or else `Err`
+    // with the index where it should be inserted: the first deleted slot in
+    // its probe sequence, or the empty slot that ends it. The table must not
+    // be empty.
+    fn probe<Q>(&self, hash: u64, value: &Q) -> Result<usize, usize>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Eq,
+    {
+        let mask = self.table.len() - 1;
+        let mut index = hash as usize & mask;
+        let mut first_deleted = None;
+        // The load factor guarantees there is at least one empty slot, which
+        // ends every probe sequence.
+        loop {
+            match &self.table[index] {
+                Slot::Empty => return Err(first_deleted.unwrap_or(index)),
+                Slot::Deleted => {
+                    first_deleted = first_deleted.or(Some(index));
+                }
+                Slot::Full(t) if t.borrow() == value => return Ok(index),
+                Slot::Full(_) => {}
+            }
+            index = (index + 1) & mask;
+        }
+    }
+
+    fn find_insert_slot(&self, hash: u64) -> usize {
+        let mask = self.table.len() - 1;
+        let mut index = hash as usize & mask;
+        loop {
+            match self.table[index] {
+                Slot::Full(_) => index = (index + 1) & mask,
+                _ => return index,
+            }
+        }
+    }
+}
+
+// Tables hold at most 7/8ths of their slots, so that probing always finds an
+// empty slot quickly.
+fn max_load(table_size: usize) -> usize {
+    table_size - table_size / 8
+}
+
+fn table_size_for(capacity: usize) -> usize {
+    if capacity == 0 {
+        return 0;
+    }
+    let size = capacity
+        .checked_mul(8)
+        .map(|n| n / 7 + 1)
+        .and_then(usize::checked_next_power_of_two)
+        .expect("capacity overflow");
+    size.max(8)
+}
+
+fn new_table<T>(size: usize, bump: &Bump) -> Vec<'_, Slot<T>> {
+    let mut table = Vec::with_capacity_in(size, bump);
+    table.extend((0..size).map(|_| Slot::Empty));
+    table
+}
+
+impl<'bump, T, S> Extend<T> for HashSet<'bump, T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for t in iter {
+            self.insert(t);
+        }
+    }
+}
+
+impl<'a, 'bump, T, S> IntoIterator for &'a HashSet<'bump, T, S> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'bump, T: fmt::Debug, S> fmt::Debug for HashSet<'bump, T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// An iterator over the elements of a `HashSet`.
+///
+/// This struct is created by the [`iter`] method on [`HashSet`].
+///
+/// [`HashSet`]: struct.HashSet.html
+/// [`iter`]: struct.HashSet.html#method.iter
+pub struct Iter<'a, T> {
+    slots: slice::Iter<'a, Slot<T>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        for slot in &mut self.slots {
+            if let Slot::Full(t) = slot {
+                self.remaining -= 1;
+                return Some(t);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            slots: self.slots.clone(),
+            remaining: self.remaining,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for Iter<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
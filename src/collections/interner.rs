@@ -0,0 +1,121 @@
+//! A string interner whose strings are allocated inside a `Bump` arena.
+//!
+//! # Examples
+//!
+//! ```
+//! use bumpalo::{Bump, collections::Interner};
+//!
+//! let b = Bump::new();
+//! let mut interner = Interner::new_in(&b);
+//!
+//! let a = interner.intern("hello");
+//! let b = interner.intern(&String::from("hello"));
+//!
+//! // Interning the same contents twice returns the same allocation.
+//! assert!(std::ptr::eq(a, b));
+//! ```
+
+use crate::Bump;
+use core_alloc::collections::BTreeSet;
+
+/// A string interner backed by a `Bump` arena.
+///
+/// Each distinct string is copied into the arena once; interning a string
+/// whose contents have already been interned returns the existing arena
+/// allocation instead of making a new one. Because of this, interned strings
+/// can be compared for equality by pointer.
+///
+/// The lookup table itself lives on the global heap, and is freed when the
+/// `Interner` is dropped. The interned strings live as long as the arena.
+#[derive(Debug)]
+pub struct Interner<'bump> {
+    bump: &'bump Bump,
+    strings: BTreeSet<&'bump str>,
+}
+
+impl<'bump> Interner<'bump> {
+    /// Constructs a new, empty `Interner` that allocates its strings inside
+    /// the given `Bump` arena.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Interner};
+    ///
+    /// let b = Bump::new();
+    /// let interner = Interner::new_in(&b);
+    /// assert!(interner.is_empty());
+    /// ```
+    pub fn new_in(bump: &'bump Bump) -> Interner<'bump> {
+        Interner {
+            bump,
+            strings: BTreeSet::new(),
+        }
+    }
+
+    /// Returns the arena-allocated copy of `s`, copying it into the arena if
+    /// it hasn't been interned yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if reserving space for the string fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Interner};
+    ///
+    /// let b = Bump::new();
+    /// let mut interner = Interner::new_in(&b);
+    ///
+    /// let foo = interner.intern("foo");
+    /// let bar = interner.intern("bar");
+    /// assert_eq!(foo, "foo");
+    /// assert!(!std::ptr::eq(foo, bar));
+    /// assert!(std::ptr::eq(foo, interner.intern("foo")));
+    /// assert_eq!(interner.len(), 2);
+    /// ```
+    pub fn intern(&mut self, s: &str) -> &'bump str {
+        if let Some(interned) = self.strings.get(s) {
+            return interned;
+        }
+
+        let interned: &'bump str = self.bump.alloc_str(s);
+        self.strings.insert(interned);
+        interned
+    }
+
+    /// Returns the arena-allocated copy of `s` if it has already been
+    /// interned, without interning it otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Interner};
+    ///
+    /// let b = Bump::new();
+    /// let mut interner = Interner::new_in(&b);
+    ///
+    /// assert_eq!(interner.get("foo"), None);
+    /// let foo = interner.intern("foo");
+    /// assert!(std::ptr::eq(interner.get("foo").unwrap(), foo));
+    /// ```
+    pub fn get(&self, s: &str) -> Option<&'bump str> {
+        self.strings.get(s).copied()
+    }
+
+    /// Returns the number of distinct strings that have been interned.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns `true` if no strings have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Returns the `Bump` arena that this interner allocates its strings in.
+    pub fn bump(&self) -> &'bump Bump {
+        self.bump
+    }
+}
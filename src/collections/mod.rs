@@ -21,6 +21,12 @@ mod str;
 pub mod string;
 pub use self::string::String;
 
+pub mod interner;
+pub use self::interner::Interner;
+
+pub mod hash_set;
+pub use self::hash_set::HashSet;
+
 // pub mod binary_heap;
 // mod btree;
 // pub mod linked_list;
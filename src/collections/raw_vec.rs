@@ -56,6 +56,10 @@ pub struct RawVec<'a, T> {
     a: &'a Bump,
 }
 
+// Amortized growth multiplies the capacity by at least this much, unless the
+// caller asks for a different factor.
+const DEFAULT_GROWTH_FACTOR: usize = 2;
+
 impl<'a, T> RawVec<'a, T> {
     /// Like `new` but parameterized over the choice of allocator for
     /// the returned RawVec.
@@ -87,33 +91,50 @@ impl<'a, T> RawVec<'a, T> {
         RawVec::allocate_in(cap, true, a)
     }
 
-    fn allocate_in(cap: usize, zeroed: bool, mut a: &'a Bump) -> Self {
-        unsafe {
-            let elem_size = mem::size_of::<T>();
+    /// The same as `with_capacity_in`, but returns on errors instead of
+    /// panicking or aborting.
+    #[inline]
+    pub fn try_with_capacity_in(cap: usize, a: &'a Bump) -> Result<Self, CollectionAllocErr> {
+        RawVec::try_allocate_in(cap, false, a)
+    }
 
-            let alloc_size = cap
-                .checked_mul(elem_size)
-                .unwrap_or_else(|| capacity_overflow());
-            alloc_guard(alloc_size).unwrap_or_else(|_| capacity_overflow());
+    fn allocate_in(cap: usize, zeroed: bool, a: &'a Bump) -> Self {
+        match RawVec::try_allocate_in(cap, zeroed, a) {
+            Ok(raw) => raw,
+            Err(CapacityOverflow) => capacity_overflow(),
+            // `try_allocate_in` only reports an allocation error after the
+            // layout has been successfully computed.
+            Err(AllocErr) => handle_alloc_error(Layout::array::<T>(cap).unwrap()),
+        }
+    }
 
-            // handles ZSTs and `cap = 0` alike
-            let ptr = if alloc_size == 0 {
+    fn try_allocate_in(
+        cap: usize,
+        zeroed: bool,
+        mut a: &'a Bump,
+    ) -> Result<Self, CollectionAllocErr> {
+        unsafe {
+            let layout = Layout::array::<T>(cap).map_err(|_| CapacityOverflow)?;
+            // Older versions of `Layout` don't reject sizes over `isize::MAX`,
+            // and `alloc_guard` only checks them on 32-bit targets.
+            if layout.size() > isize::MAX as usize {
+                return Err(CapacityOverflow);
+            }
+            alloc_guard(layout.size())?;
+
+            // handles ZSTs and `cap = 0` alike, without touching the arena
+            let ptr = if layout.size() == 0 {
                 NonNull::<T>::dangling()
             } else {
-                let align = mem::align_of::<T>();
-                let layout = Layout::from_size_align(alloc_size, align).unwrap();
                 let result = if zeroed {
                     a.alloc_zeroed(layout)
                 } else {
                     Alloc::alloc(&mut a, layout)
                 };
-                match result {
-                    Ok(ptr) => ptr.cast(),
-                    Err(_) => handle_alloc_error(layout),
-                }
+                result?.cast()
             };
 
-            RawVec { ptr, cap, a }
+            Ok(RawVec { ptr, cap, a })
         }
     }
 }
@@ -362,15 +383,17 @@ impl<'a, T> RawVec<'a, T> {
         &self,
         used_cap: usize,
         needed_extra_cap: usize,
+        growth_factor: usize,
     ) -> Result<usize, CollectionAllocErr> {
         // Nothing we can really do about these checks :(
         let required_cap = used_cap
             .checked_add(needed_extra_cap)
             .ok_or(CapacityOverflow)?;
-        // Cannot overflow, because `cap <= isize::MAX`, and type of `cap` is `usize`.
-        let double_cap = self.cap * 2;
-        // `double_cap` guarantees exponential growth.
-        Ok(cmp::max(double_cap, required_cap))
+        // Growing by the factor guarantees exponential growth. If that would
+        // overflow, fall back to the required capacity, which will fail the
+        // layout computation later if it is too large as well.
+        let grown_cap = self.cap.checked_mul(growth_factor).unwrap_or(required_cap);
+        Ok(cmp::max(grown_cap, required_cap))
     }
 
     /// The same as `reserve`, but returns on errors instead of panicking or aborting.
@@ -379,7 +402,12 @@ impl<'a, T> RawVec<'a, T> {
         used_cap: usize,
         needed_extra_cap: usize,
     ) -> Result<(), CollectionAllocErr> {
-        self.reserve_internal(used_cap, needed_extra_cap, Fallible, Amortized)
+        self.reserve_internal(
+            used_cap,
+            needed_extra_cap,
+            Fallible,
+            Amortized(DEFAULT_GROWTH_FACTOR),
+        )
     }
 
     /// Ensures that the buffer contains at least enough space to hold
@@ -435,7 +463,24 @@ impl<'a, T> RawVec<'a, T> {
     /// # }
     /// ```
     pub fn reserve(&mut self, used_cap: usize, needed_extra_cap: usize) {
-        match self.reserve_internal(used_cap, needed_extra_cap, Infallible, Amortized) {
+        self.reserve_with_growth_factor(used_cap, needed_extra_cap, DEFAULT_GROWTH_FACTOR)
+    }
+
+    /// The same as `reserve`, but if the buffer has to grow, its capacity is
+    /// multiplied by at least `growth_factor` instead of doubled.
+    pub fn reserve_with_growth_factor(
+        &mut self,
+        used_cap: usize,
+        needed_extra_cap: usize,
+        growth_factor: usize,
+    ) {
+        debug_assert!(growth_factor >= 2);
+        match self.reserve_internal(
+            used_cap,
+            needed_extra_cap,
+            Infallible,
+            Amortized(growth_factor),
+        ) {
             Err(CapacityOverflow) => capacity_overflow(),
             Err(AllocErr) => unreachable!(),
             Ok(()) => { /* yay */ }
@@ -477,7 +522,7 @@ impl<'a, T> RawVec<'a, T> {
             }
 
             let new_cap = self
-                .amortized_new_size(used_cap, needed_extra_cap)
+                .amortized_new_size(used_cap, needed_extra_cap, DEFAULT_GROWTH_FACTOR)
                 .unwrap_or_else(|_| capacity_overflow());
 
             // Here, `cap < used_cap + needed_extra_cap <= new_cap`
@@ -592,7 +637,8 @@ use self::Fallibility::*;
 
 enum ReserveStrategy {
     Exact,
-    Amortized,
+    // Grows by at least the given factor.
+    Amortized(usize),
 }
 
 use self::ReserveStrategy::*;
@@ -624,7 +670,9 @@ impl<'a, T> RawVec<'a, T> {
                 Exact => used_cap
                     .checked_add(needed_extra_cap)
                     .ok_or(CapacityOverflow)?,
-                Amortized => self.amortized_new_size(used_cap, needed_extra_cap)?,
+                Amortized(growth_factor) => {
+                    self.amortized_new_size(used_cap, needed_extra_cap, growth_factor)?
+                }
             };
             let new_layout = Layout::array::<T>(new_cap).map_err(|_| CapacityOverflow)?;
 
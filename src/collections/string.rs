@@ -719,6 +719,34 @@ impl<'bump> String<'bump> {
         t
     }
 
+    /// Construct a new `String<'bump>` holding `n` copies of `pattern`, one
+    /// after the other.
+    ///
+    /// Like [`Vec::repeat_in`], this allocates exactly enough space for the
+    /// result once, and fills it by doubling.
+    ///
+    /// [`Vec::repeat_in`]: ../vec/struct.Vec.html#method.repeat_in
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result's length would overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::String};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let indent = String::repeat_in("  ", 4, &b);
+    /// assert_eq!(indent, "        ");
+    /// ```
+    pub fn repeat_in(pattern: &str, n: usize, bump: &'bump Bump) -> String<'bump> {
+        let bytes = Vec::repeat_in(pattern.as_bytes(), n, bump);
+        // Safe because copies of a whole `str` are valid UTF-8.
+        unsafe { String::from_utf8_unchecked(bytes) }
+    }
+
     /// Construct a new `String<'bump>` from an iterator of `char`s.
     ///
     /// # Examples
@@ -1295,35 +1323,57 @@ impl<'bump> String<'bump> {
     where
         F: FnMut(char) -> bool,
     {
+        // Sets the length to just the retained characters on the way out,
+        // including when `f` panics, so the string stays valid UTF-8.
+        struct SetLenOnDrop<'a, 'bump> {
+            s: &'a mut String<'bump>,
+            idx: usize,
+            del_bytes: usize,
+        }
+
+        impl<'a, 'bump> Drop for SetLenOnDrop<'a, 'bump> {
+            fn drop(&mut self) {
+                let new_len = self.idx - self.del_bytes;
+                debug_assert!(new_len <= self.s.len());
+                unsafe { self.s.vec.set_len(new_len) };
+            }
+        }
+
         let len = self.len();
-        let mut del_bytes = 0;
-        let mut idx = 0;
+        let mut guard = SetLenOnDrop {
+            s: self,
+            idx: 0,
+            del_bytes: 0,
+        };
 
-        while idx < len {
-            let ch = unsafe { self.get_unchecked(idx..len).chars().next().unwrap() };
+        while guard.idx < len {
+            let ch = unsafe {
+                guard
+                    .s
+                    .get_unchecked(guard.idx..len)
+                    .chars()
+                    .next()
+                    .unwrap()
+            };
             let ch_len = ch.len_utf8();
 
             if !f(ch) {
-                del_bytes += ch_len;
-            } else if del_bytes > 0 {
+                guard.del_bytes += ch_len;
+            } else if guard.del_bytes > 0 {
                 unsafe {
                     ptr::copy(
-                        self.vec.as_ptr().add(idx),
-                        self.vec.as_mut_ptr().add(idx - del_bytes),
+                        guard.s.vec.as_ptr().add(guard.idx),
+                        guard.s.vec.as_mut_ptr().add(guard.idx - guard.del_bytes),
                         ch_len,
                     );
                 }
             }
 
             // Point idx to the next char
-            idx += ch_len;
+            guard.idx += ch_len;
         }
 
-        if del_bytes > 0 {
-            unsafe {
-                self.vec.set_len(len - del_bytes);
-            }
-        }
+        drop(guard);
     }
 
     /// Inserts a character into this `String` at a byte position.
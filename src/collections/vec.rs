@@ -87,7 +87,7 @@ use super::raw_vec::RawVec;
 use crate::collections::CollectionAllocErr;
 use crate::Bump;
 use core::borrow::{Borrow, BorrowMut};
-use core::cmp::Ordering;
+use core::cmp::{self, Ordering};
 use core::fmt;
 use core::hash::{self, Hash};
 use core::iter::FusedIterator;
@@ -402,6 +402,25 @@ macro_rules! vec {
 /// when you just want to provide a read access. The same goes for [`String`] and
 /// [`&str`].
 ///
+/// Because `Vec<'bump, T>` dereferences to `[T]`, all of the slice methods can
+/// also be called directly on a vector:
+///
+/// ```
+/// use bumpalo::{Bump, collections::Vec};
+///
+/// let b = Bump::new();
+///
+/// let mut v = bumpalo::vec![in &b; 5, 1, 4, 2, 3];
+/// v.sort();
+/// assert_eq!(v, [1, 2, 3, 4, 5]);
+///
+/// // Find the first element that is not less than 3.
+/// assert_eq!(v.partition_point(|&x| x < 3), 2);
+///
+/// assert_eq!(v.windows(2).count(), 4);
+/// assert_eq!(&v[1..3], &[2, 3]);
+/// ```
+///
 /// # Capacity and reallocation
 ///
 /// The capacity of a vector is the amount of space allocated for any future
@@ -497,10 +516,17 @@ macro_rules! vec {
 /// not break, however: using `unsafe` code to write to the excess capacity,
 /// and then increasing the length to match, is always valid.
 ///
+/// Unlike values allocated directly with [`Bump::alloc`], the elements of a
+/// `Vec` are dropped like those of a `std::vec::Vec`: when they are removed by
+/// `truncate`, `clear`, or `pop` (and not returned), when the `Vec` itself is
+/// dropped, and when a consuming iterator is dropped before it is exhausted.
+/// Only the buffer stays behind in the arena.
+///
 /// `Vec` does not currently guarantee the order in which elements are dropped.
 /// The order has changed in the past and may change again.
 ///
 /// [`vec!`]: ../../macro.vec.html
+/// [`Bump::alloc`]: ../../struct.Bump.html#method.alloc
 /// [`Index`]: https://doc.rust-lang.org/nightly/std/ops/trait.Index.html
 /// [`String`]: https://doc.rust-lang.org/nightly/std/string/struct.String.html
 /// [`&str`]: https://doc.rust-lang.org/nightly/std/primitive.str.html
@@ -585,8 +611,53 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
         }
     }
 
+    /// Constructs a new, empty `Vec<'bump, T>` with the specified capacity,
+    /// returning an error instead of panicking if the capacity overflows or
+    /// the allocation fails.
+    ///
+    /// Like [`with_capacity_in`], a `capacity` of 0 does not allocate.
+    ///
+    /// [`with_capacity_in`]: #method.with_capacity_in
+    ///
+    /// # Errors
+    ///
+    /// Returns `CollectionAllocErr::CapacityOverflow` if `capacity` elements
+    /// would take up more than `isize::MAX` bytes, and
+    /// `CollectionAllocErr::AllocErr` if the arena fails to allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::{CollectionAllocErr, Vec}};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let vec: Vec<u64> = Vec::try_with_capacity_in(10, &b).unwrap();
+    /// assert!(vec.capacity() >= 10);
+    ///
+    /// let err = Vec::<u64>::try_with_capacity_in(usize::max_value(), &b).unwrap_err();
+    /// assert_eq!(err, CollectionAllocErr::CapacityOverflow);
+    /// ```
+    #[inline]
+    pub fn try_with_capacity_in(
+        capacity: usize,
+        bump: &'bump Bump,
+    ) -> Result<Vec<'bump, T>, CollectionAllocErr> {
+        Ok(Vec {
+            buf: RawVec::try_with_capacity_in(capacity, bump)?,
+            len: 0,
+        })
+    }
+
     /// Construct a new `Vec` from the given iterator's items.
     ///
+    /// If the iterator's `size_hint` is exact, that is its lower and upper
+    /// bounds are equal, the vector's storage is allocated once, up front, and
+    /// filled without checking for growth. Otherwise the vector grows as
+    /// needed, like with [`extend`](#method.extend). An iterator whose exact
+    /// `size_hint` turns out to be wrong still produces the right result, it
+    /// just loses the benefit of preallocation.
+    ///
     /// # Examples
     ///
     /// ```
@@ -596,9 +667,35 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
     /// let b = Bump::new();
     /// let v = Vec::from_iter_in(iter::repeat(7).take(3), &b);
     /// assert_eq!(v, [7, 7, 7]);
+    /// assert_eq!(v.capacity(), 3);
     /// ```
     pub fn from_iter_in<I: IntoIterator<Item = T>>(iter: I, bump: &'bump Bump) -> Vec<'bump, T> {
-        let mut v = Vec::new_in(bump);
+        let mut iter = iter.into_iter();
+        let (lower, upper) = iter.size_hint();
+        if upper != Some(lower) {
+            let mut v = Vec::new_in(bump);
+            v.extend(iter);
+            return v;
+        }
+
+        let mut v = Vec::with_capacity_in(lower, bump);
+        unsafe {
+            let mut ptr = v.as_mut_ptr();
+            // Increment the length in every step in case `next()` panics.
+            let mut local_len = SetLenOnDrop::new(&mut v.len);
+            for _ in 0..lower {
+                match iter.next() {
+                    Some(t) => {
+                        ptr::write(ptr, t);
+                        ptr = ptr.add(1);
+                        local_len.increment_len(1);
+                    }
+                    None => break,
+                }
+            }
+        }
+
+        // The size hint could have been wrong, so pick up any stragglers.
         v.extend(iter);
         v
     }
@@ -713,6 +810,45 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
         self.buf.reserve(self.len, additional);
     }
 
+    /// Like [`reserve`](#method.reserve), but if the vector has to grow, its
+    /// capacity is multiplied by at least `growth_factor` instead of doubled.
+    ///
+    /// When the vector's buffer is the most recent allocation in its arena, it
+    /// grows in place and nothing is wasted. Otherwise, each time it grows, the
+    /// old buffer stays behind in the arena until the arena is reset. A larger
+    /// growth factor means the vector grows fewer times, and so leaves fewer
+    /// old buffers behind, at the cost of more unused capacity.
+    ///
+    /// The factor only applies to this call. The vector doesn't remember it,
+    /// so [`push`](#method.push) and [`reserve`](#method.reserve) go on
+    /// doubling its capacity. A factor set once per vector, say by a
+    /// `with_growth_factor_in` constructor, would have to be stored in the
+    /// vector, making every `Vec` and `String` a word larger whether or not it
+    /// uses one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `growth_factor` is less than 2, or if the new capacity
+    /// overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let mut vec = bumpalo::vec![in &b; 1, 2];
+    /// assert_eq!(vec.capacity(), 2);
+    /// vec.reserve_with_growth_factor(1, 4);
+    /// assert_eq!(vec.capacity(), 8);
+    /// ```
+    pub fn reserve_with_growth_factor(&mut self, additional: usize, growth_factor: usize) {
+        assert!(growth_factor >= 2, "growth factor must be at least 2");
+        self.buf
+            .reserve_with_growth_factor(self.len, additional, growth_factor);
+    }
+
     /// Reserves the minimum capacity for exactly `additional` more elements to
     /// be inserted in the given `Vec<'bump, T>`. After calling `reserve_exact`,
     /// capacity will be greater than or equal to `self.len() + additional`.
@@ -796,6 +932,11 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
     /// It will drop down as close as possible to the length but the allocator
     /// may still inform the vector that there is space for a few more elements.
     ///
+    /// If the vector's buffer is the most recent allocation in its arena, the
+    /// unused capacity is handed back to the arena, and later allocations will
+    /// reuse it. Otherwise, the unused capacity stays reserved until the arena
+    /// is reset.
+    ///
     /// # Examples
     ///
     /// ```
@@ -815,6 +956,36 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
         }
     }
 
+    /// Shrinks the capacity of the vector with a lower bound.
+    ///
+    /// The capacity will remain at least as large as both the length and the
+    /// supplied value. If the current capacity is already less than the lower
+    /// bound, this is a no-op.
+    ///
+    /// Unused capacity is handed back to the arena in the same cases as for
+    /// [`shrink_to_fit`](#method.shrink_to_fit).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let mut vec = Vec::with_capacity_in(10, &b);
+    /// vec.extend([1, 2, 3].iter().cloned());
+    /// assert_eq!(vec.capacity(), 10);
+    /// vec.shrink_to(4);
+    /// assert!(vec.capacity() >= 4);
+    /// vec.shrink_to(0);
+    /// assert!(vec.capacity() >= 3);
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        if self.capacity() > min_capacity {
+            self.buf.shrink_to_fit(cmp::max(self.len, min_capacity));
+        }
+    }
+
     /// Converts the vector into `&'bump [T]`.
     ///
     /// # Examples
@@ -862,6 +1033,39 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
         unsafe { slice::from_raw_parts_mut(ptr, len) }
     }
 
+    /// Moves the elements into a new `std::vec::Vec` on the global heap, which
+    /// can outlive the arena.
+    ///
+    /// The elements are moved rather than cloned, so this works for any `T`.
+    /// The new vector's capacity is exactly the length. The arena's buffer is
+    /// given back to the arena if it was its most recent allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let owned = {
+    ///     let b = Bump::new();
+    ///     let mut v = Vec::new_in(&b);
+    ///     v.push(String::from("hello"));
+    ///     v.push(String::from("world"));
+    ///     v.into_std_vec()
+    /// };
+    /// assert_eq!(owned, ["hello", "world"]);
+    /// ```
+    pub fn into_std_vec(mut self) -> crate::core_alloc::vec::Vec<T> {
+        let len = self.len();
+        let mut vec = crate::core_alloc::vec::Vec::with_capacity(len);
+        unsafe {
+            ptr::copy_nonoverlapping(self.as_ptr(), vec.as_mut_ptr(), len);
+            // The elements now belong to `vec`.
+            self.set_len(0);
+            vec.set_len(len);
+        }
+        vec
+    }
+
     /// Shortens the vector, keeping the first `len` elements and dropping
     /// the rest.
     ///
@@ -974,6 +1178,71 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
         self
     }
 
+    /// Splits the vector's contents into a slice of `N`-element arrays,
+    /// starting at the beginning, and a remainder slice with length strictly
+    /// less than `N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let v = bumpalo::vec![in &b; 1, 2, 3, 4, 5];
+    /// let (chunks, remainder) = v.as_chunks::<2>();
+    /// assert_eq!(chunks, &[[1, 2], [3, 4]]);
+    /// assert_eq!(remainder, &[5]);
+    /// ```
+    #[inline]
+    pub fn as_chunks<const N: usize>(&self) -> (&[[T; N]], &[T]) {
+        assert!(N != 0, "chunk size must be non-zero");
+        let len = self.len() / N;
+        let (chunks, remainder) = self.split_at(len * N);
+        // Safe because `[T; N]` has the same layout as `N` consecutive `T`s,
+        // and `chunks` holds exactly `len * N` of them.
+        let chunks = unsafe { slice::from_raw_parts(chunks.as_ptr() as *const [T; N], len) };
+        (chunks, remainder)
+    }
+
+    /// Splits the vector's contents into a mutable slice of `N`-element
+    /// arrays, starting at the beginning, and a mutable remainder slice with
+    /// length strictly less than `N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let mut v = bumpalo::vec![in &b; 1, 1, 2, 2, 3];
+    /// let (chunks, remainder) = v.as_chunks_mut::<2>();
+    /// for chunk in chunks {
+    ///     chunk.swap(0, 1);
+    ///     chunk[1] *= 10;
+    /// }
+    /// remainder[0] = 0;
+    /// assert_eq!(v, [1, 10, 2, 20, 0]);
+    /// ```
+    #[inline]
+    pub fn as_chunks_mut<const N: usize>(&mut self) -> (&mut [[T; N]], &mut [T]) {
+        assert!(N != 0, "chunk size must be non-zero");
+        let len = self.len() / N;
+        let (chunks, remainder) = self.split_at_mut(len * N);
+        // Safe for the same reasons as in `as_chunks`.
+        let chunks = unsafe { slice::from_raw_parts_mut(chunks.as_mut_ptr() as *mut [T; N], len) };
+        (chunks, remainder)
+    }
+
     /// Sets the length of a vector.
     ///
     /// This will explicitly set the size of the vector, without actually
@@ -1185,10 +1454,108 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
     where
         F: FnMut(&T) -> bool,
     {
-        self.drain_filter(|x| !f(x));
+        self.extract_if(|x| !f(x)).for_each(drop);
     }
 
-    fn drain_filter<'a, F>(&'a mut self, filter: F) -> DrainFilter<'a, 'bump, T, F>
+    /// Returns the index of the partition point according to the given
+    /// predicate (the index of the first element of the second partition).
+    ///
+    /// The vector is assumed to be partitioned according to the given
+    /// predicate. This means that all elements for which the predicate returns
+    /// true are at the start of the vector and all elements for which the
+    /// predicate returns false are at the end. For example, `[7, 15, 3, 5, 4,
+    /// 12, 6]` is partitioned under the predicate `x % 2 != 0` (all odd numbers
+    /// are at the start, all even at the end).
+    ///
+    /// If this vector is not partitioned, the returned result is unspecified
+    /// and meaningless, as this method performs a kind of binary search.
+    ///
+    /// This is the same as the slice method of the same name, which is only
+    /// available from Rust 1.52 on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let v = bumpalo::vec![in &b; 1, 2, 3, 3, 5, 6, 7];
+    /// let i = v.partition_point(|&x| x < 5);
+    ///
+    /// assert_eq!(i, 4);
+    /// assert!(v[..i].iter().all(|&x| x < 5));
+    /// assert!(v[i..].iter().all(|&x| !(x < 5)));
+    /// ```
+    pub fn partition_point<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.binary_search_by(|x| {
+            if pred(x) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        })
+        .unwrap_or_else(|i| i)
+    }
+
+    /// Creates an iterator which uses a closure to determine if an element
+    /// should be removed.
+    ///
+    /// If the closure returns `true`, the element is removed from the vector
+    /// and yielded by the iterator. If the closure returns `false`, the
+    /// element stays in the vector and is not yielded. The closure is given a
+    /// mutable reference to each element, so it may also modify the elements
+    /// it keeps.
+    ///
+    /// The elements are visited once each, in order, and the retained elements
+    /// are shifted down to fill the gaps as the iterator goes, preserving their
+    /// order. If the iterator is dropped before it is exhausted, for example
+    /// because iteration stopped early, the elements it has not yet visited are
+    /// retained without being passed to the closure.
+    ///
+    /// This is equivalent to the following code, but the elements are moved
+    /// out of the vector in a single pass and without shifting the remaining
+    /// elements more than once:
+    ///
+    /// ```
+    /// # use bumpalo::{Bump, collections::Vec};
+    /// # let b = Bump::new();
+    /// # let some_predicate = |x: &mut i32| { *x == 2 || *x == 3 || *x == 6 };
+    /// # let mut vec = bumpalo::vec![in &b; 1, 2, 3, 4, 5, 6];
+    /// let mut i = 0;
+    /// while i < vec.len() {
+    ///     if some_predicate(&mut vec[i]) {
+    ///         let val = vec.remove(i);
+    ///         // your code here
+    ///     } else {
+    ///         i += 1;
+    ///     }
+    /// }
+    /// # assert_eq!(vec, [1, 4, 5]);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// Splitting a vector into evens and odds, reusing the original vector
+    /// for the odds:
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let mut numbers = bumpalo::vec![in &b; 1, 2, 3, 4, 5, 6, 8, 9, 11, 13, 14, 15];
+    ///
+    /// let evens = Vec::from_iter_in(numbers.extract_if(|x| *x % 2 == 0), &b);
+    /// let odds = numbers;
+    ///
+    /// assert_eq!(evens, [2, 4, 6, 8, 14]);
+    /// assert_eq!(odds, [1, 3, 5, 9, 11, 13, 15]);
+    /// ```
+    pub fn extract_if<'a, F>(&'a mut self, filter: F) -> ExtractIf<'a, 'bump, T, F>
     where
         F: FnMut(&mut T) -> bool,
     {
@@ -1199,7 +1566,7 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
             self.set_len(0);
         }
 
-        DrainFilter {
+        ExtractIf {
             vec: self,
             idx: 0,
             del: 0,
@@ -1299,6 +1666,37 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
         }
     }
 
+    /// Appends an element to the back of a collection, or returns it back if
+    /// the vector cannot grow to hold it.
+    ///
+    /// Unlike `push`, running out of space in the arena is not fatal: if the
+    /// vector is full and growing its buffer fails, `value` is returned in the
+    /// `Err` and the vector is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let mut vec = bumpalo::vec![in &b; 1, 2];
+    /// assert_eq!(vec.try_push(3), Ok(()));
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn try_push(&mut self, value: T) -> Result<(), T> {
+        if self.len == self.buf.cap() && self.try_reserve(1).is_err() {
+            return Err(value);
+        }
+        unsafe {
+            let end = self.as_mut_ptr().add(self.len);
+            ptr::write(end, value);
+            self.len += 1;
+        }
+        Ok(())
+    }
+
     /// Removes the last element from a vector and returns it, or [`None`] if it
     /// is empty.
     ///
@@ -1322,7 +1720,7 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
         } else {
             unsafe {
                 self.len -= 1;
-                Some(ptr::read(self.get_unchecked(self.len())))
+                Some(ptr::read(self.as_ptr().add(self.len())))
             }
         }
     }
@@ -1357,10 +1755,10 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
     /// Appends elements to `Self` from other buffer.
     #[inline]
     unsafe fn append_elements(&mut self, other: *const [T]) {
-        let count = (*other).len();
+        let count = (&(*other)).len();
         self.reserve(count);
         let len = self.len();
-        ptr::copy_nonoverlapping(other as *const T, self.get_unchecked_mut(len), count);
+        ptr::copy_nonoverlapping(other as *const T, self.as_mut_ptr().add(len), count);
         self.len += count;
     }
 
@@ -1616,7 +2014,10 @@ impl<'bump, T: 'bump + Clone> Vec<'bump, T> {
     /// Clones and appends all elements in a slice to the `Vec`.
     ///
     /// Iterates over the slice `other`, clones each element, and then appends
-    /// it to this `Vec`. The `other` vector is traversed in-order.
+    /// it to this `Vec`. The `other` vector is traversed in-order. Space for
+    /// all of `other` is reserved before the first clone. If a clone panics,
+    /// the clones already appended by this call are dropped, and the vector
+    /// is left as it was before the call.
     ///
     /// Note that this function is same as [`extend`] except that it is
     /// specialized to work with slices instead. If and when Rust gets
@@ -1637,7 +2038,184 @@ impl<'bump, T: 'bump + Clone> Vec<'bump, T> {
     ///
     /// [`extend`]: #method.extend
     pub fn extend_from_slice(&mut self, other: &[T]) {
-        self.extend(other.iter().cloned())
+        // Truncates the vector back to `len` when dropped, which only happens
+        // if a clone panics.
+        struct TruncateOnUnwind<'a, 'bump, T> {
+            vec: &'a mut Vec<'bump, T>,
+            len: usize,
+        }
+
+        impl<'a, 'bump, T> Drop for TruncateOnUnwind<'a, 'bump, T> {
+            fn drop(&mut self) {
+                self.vec.truncate(self.len);
+            }
+        }
+
+        self.reserve(other.len());
+        let len = self.len;
+        let guard = TruncateOnUnwind { vec: self, len };
+        for x in other {
+            unsafe {
+                let end = guard.vec.as_mut_ptr().add(guard.vec.len);
+                ptr::write(end, x.clone());
+                guard.vec.len += 1;
+            }
+        }
+        mem::forget(guard);
+    }
+
+    /// Clones the elements in the range `src` and appends them to the end of
+    /// the vector.
+    ///
+    /// Growing the vector may move its buffer, so the source elements are
+    /// cloned from wherever they live after the space for the copies has been
+    /// reserved. The range is always in bounds of the original elements, so
+    /// it never overlaps the copies being written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let mut vec = bumpalo::vec![in &b; 0, 1, 2, 3, 4];
+    /// vec.extend_from_within(2..);
+    /// assert_eq!(vec, [0, 1, 2, 3, 4, 2, 3, 4]);
+    ///
+    /// vec.extend_from_within(..2);
+    /// assert_eq!(vec, [0, 1, 2, 3, 4, 2, 3, 4, 0, 1]);
+    ///
+    /// vec.extend_from_within(4..8);
+    /// assert_eq!(vec, [0, 1, 2, 3, 4, 2, 3, 4, 0, 1, 4, 2, 3, 4]);
+    /// ```
+    pub fn extend_from_within<R>(&mut self, src: R)
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.len();
+        let start = match src.start_bound() {
+            Included(&n) => n,
+            Excluded(&n) => n
+                .checked_add(1)
+                .expect("attempted to index slice from after maximum usize"),
+            Unbounded => 0,
+        };
+        let end = match src.end_bound() {
+            Included(&n) => n
+                .checked_add(1)
+                .expect("attempted to index slice up to maximum usize"),
+            Excluded(&n) => n,
+            Unbounded => len,
+        };
+        assert!(
+            start <= end,
+            "slice index starts at {} but ends at {}",
+            start,
+            end
+        );
+        assert!(
+            end <= len,
+            "range end index {} out of range for slice of length {}",
+            end,
+            len
+        );
+
+        self.reserve(end - start);
+
+        unsafe {
+            let base = self.as_mut_ptr();
+            let mut dst = base.add(len);
+            // Bump the length after each write, so that if a `clone` panics,
+            // only the elements cloned so far are kept.
+            let mut local_len = SetLenOnDrop::new(&mut self.len);
+            for i in start..end {
+                ptr::write(dst, (*base.add(i)).clone());
+                dst = dst.add(1);
+                local_len.increment_len(1);
+            }
+        }
+    }
+
+    /// Clones the elements into a new `std::vec::Vec` on the global heap, which
+    /// can outlive the arena.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let owned = {
+    ///     let b = Bump::new();
+    ///     let v = bumpalo::vec![in &b; 1, 2, 3];
+    ///     v.to_std_vec()
+    /// };
+    /// assert_eq!(owned, vec![1, 2, 3]);
+    /// ```
+    pub fn to_std_vec(&self) -> crate::core_alloc::vec::Vec<T> {
+        self.as_slice().to_vec()
+    }
+}
+
+impl<'bump, T: 'bump + Copy> Vec<'bump, T> {
+    /// Constructs a new `Vec` holding `n` copies of `pattern`, one after the
+    /// other.
+    ///
+    /// The vector's storage is allocated once, with exactly enough capacity
+    /// for the result. It is then filled by copying the pattern once and
+    /// doubling the filled prefix with each further copy, so building the
+    /// result takes `O(log n)` copies rather than `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result's length would overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let v = Vec::repeat_in(&[1, 2], 3, &b);
+    /// assert_eq!(v, [1, 2, 1, 2, 1, 2]);
+    /// assert_eq!(v.capacity(), 6);
+    /// ```
+    pub fn repeat_in(pattern: &[T], n: usize, bump: &'bump Bump) -> Vec<'bump, T> {
+        if n == 0 {
+            return Vec::new_in(bump);
+        }
+
+        let capacity = pattern.len().checked_mul(n).expect("capacity overflow");
+        let mut buf = Vec::with_capacity_in(capacity, bump);
+        buf.extend_from_slice(pattern);
+
+        unsafe {
+            // Double the filled prefix while that still fits: after `k`
+            // doublings the length is `pattern.len() * 2^k`, which is at most
+            // `capacity` as long as `2^k <= n`.
+            let mut m = n >> 1;
+            while m > 0 {
+                let len = buf.len();
+                ptr::copy_nonoverlapping(buf.as_ptr(), buf.as_mut_ptr().add(len), len);
+                buf.set_len(len * 2);
+                m >>= 1;
+            }
+
+            // Fill whatever is left with a prefix of what we have so far,
+            // which is always shorter than the filled part.
+            let len = buf.len();
+            let rest = capacity - len;
+            ptr::copy_nonoverlapping(buf.as_ptr(), buf.as_mut_ptr().add(len), rest);
+            buf.set_len(capacity);
+        }
+
+        buf
     }
 }
 
@@ -1825,6 +2403,16 @@ impl<'bump, T: 'bump> ops::DerefMut for Vec<'bump, T> {
     }
 }
 
+impl<'bump, T: 'bump> Drop for Vec<'bump, T> {
+    fn drop(&mut self) {
+        unsafe {
+            // use drop for [T]
+            // The buffer itself is left for `RawVec` to handle.
+            ptr::drop_in_place(&mut self[..]);
+        }
+    }
+}
+
 impl<'bump, T: 'bump> IntoIterator for Vec<'bump, T> {
     type Item = T;
     type IntoIter = IntoIter<T>;
@@ -2154,6 +2742,13 @@ impl<'bump, T: 'bump> IntoIter<T> {
     }
 }
 
+impl<T> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        // drop any remaining elements; the buffer stays in the arena
+        self.for_each(drop);
+    }
+}
+
 unsafe impl<T: Send> Send for IntoIter<T> {}
 unsafe impl<T: Sync> Sync for IntoIter<T> {}
 
@@ -2411,9 +3006,15 @@ impl<'a, 'bump, T> Drain<'a, 'bump, T> {
     }
 }
 
-/// An iterator produced by calling `drain_filter` on Vec.
+/// An iterator which uses a closure to determine if an element should be
+/// removed.
+///
+/// This `struct` is created by [`Vec::extract_if`]. See its documentation for
+/// more.
+///
+/// [`Vec::extract_if`]: struct.Vec.html#method.extract_if
 #[derive(Debug)]
-pub struct DrainFilter<'a, 'bump: 'a, T: 'a + 'bump, F>
+pub struct ExtractIf<'a, 'bump: 'a, T: 'a + 'bump, F>
 where
     F: FnMut(&mut T) -> bool,
 {
@@ -2424,7 +3025,13 @@ where
     pred: F,
 }
 
-impl<'a, 'bump, T, F> Iterator for DrainFilter<'a, 'bump, T, F>
+/// The iterator type now returned by [`Vec::extract_if`], under its old name.
+///
+/// [`Vec::extract_if`]: struct.Vec.html#method.extract_if
+#[deprecated(note = "renamed to `ExtractIf`")]
+pub type DrainFilter<'a, 'bump, T, F> = ExtractIf<'a, 'bump, T, F>;
+
+impl<'a, 'bump, T, F> Iterator for ExtractIf<'a, 'bump, T, F>
 where
     F: FnMut(&mut T) -> bool,
 {
@@ -2434,9 +3041,12 @@ where
         unsafe {
             while self.idx != self.old_len {
                 let i = self.idx;
-                self.idx += 1;
                 let v = slice::from_raw_parts_mut(self.vec.as_mut_ptr(), self.old_len);
-                if (self.pred)(&mut v[i]) {
+                let extracted = (self.pred)(&mut v[i]);
+                // Only advance once the predicate has returned, so that if it
+                // panics, `drop` still shifts this element down and keeps it.
+                self.idx += 1;
+                if extracted {
                     self.del += 1;
                     return Some(ptr::read(&v[i]));
                 } else if self.del > 0 {
@@ -2458,13 +3068,19 @@ where
     }
 }
 
-impl<'a, 'bump, T, F> Drop for DrainFilter<'a, 'bump, T, F>
+impl<'a, 'bump, T, F> Drop for ExtractIf<'a, 'bump, T, F>
 where
     F: FnMut(&mut T) -> bool,
 {
     fn drop(&mut self) {
-        self.for_each(drop);
         unsafe {
+            // Keep the elements that were never visited, moving them down
+            // over the gap left by the extracted ones.
+            if self.idx < self.old_len && self.del > 0 {
+                let src = self.vec.as_ptr().add(self.idx);
+                let dst = self.vec.as_mut_ptr().add(self.idx - self.del);
+                ptr::copy(src, dst, self.old_len - self.idx);
+            }
             self.vec.set_len(self.old_len - self.del);
         }
     }
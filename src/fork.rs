@@ -0,0 +1,140 @@
+use crate::{Bump, ChunkFooter, CHUNK_ALIGN, FOOTER_SIZE};
+use core::alloc::Layout;
+use core::cell::Cell;
+use core::fmt;
+use core::mem::ManuallyDrop;
+use core::ops::Deref;
+use core::ptr::{self, NonNull};
+use core_alloc::alloc::dealloc;
+
+/// A child arena created by [`Bump::fork`](./struct.Bump.html#method.fork),
+/// whose allocations are all thrown away when it is dropped.
+///
+/// The child starts out allocating from the free space left in its parent's
+/// current chunk, and allocates chunks of its own from the global allocator
+/// once that runs out. Dropping the child frees its own chunks and, if the
+/// parent has not allocated anything since the fork, hands the borrowed free
+/// space back to the parent.
+///
+/// `ChildBump` dereferences to `Bump`, so it can be used anywhere a `&Bump` is
+/// expected. Because it only ever hands out shared access to that `Bump`,
+/// methods that take `&mut Bump`, such as `reset`, are not available on a
+/// child.
+pub struct ChildBump<'parent> {
+    bump: ManuallyDrop<Bump>,
+    // When the child borrowed free space from its parent's current chunk, the
+    // parent's footer and the bump pointer it had before the fork. The borrowed
+    // space is always the child's oldest chunk.
+    borrowed: Option<(NonNull<ChunkFooter>, NonNull<u8>)>,
+    parent: &'parent Bump,
+}
+
+impl<'parent> ChildBump<'parent> {
+    pub(crate) fn try_new(parent: &'parent Bump) -> Option<ChildBump<'parent>> {
+        unsafe {
+            let parent_footer = parent.current_chunk_footer.get();
+            let footer = parent_footer.as_ref();
+            let data = footer.data.as_ptr() as usize;
+            let ptr = footer.ptr.get();
+
+            // Room for a footer, at the top of the free space, with something
+            // to allocate below it.
+            let child_footer = (ptr.as_ptr() as usize)
+                .checked_sub(FOOTER_SIZE)
+                .map(|f| f & !(CHUNK_ALIGN - 1))
+                .filter(|&f| f > data);
+
+            let (current_chunk_footer, borrowed) = match child_footer {
+                Some(child_footer) => {
+                    let child_footer = child_footer as *mut ChunkFooter;
+                    ptr::write(
+                        child_footer,
+                        ChunkFooter {
+                            data: footer.data,
+                            // Only used to size the child's later chunks; the
+                            // borrowed space is never deallocated by the child.
+                            layout: Layout::from_size_align_unchecked(
+                                child_footer as usize + FOOTER_SIZE - data,
+                                CHUNK_ALIGN,
+                            ),
+                            prev: Cell::new(None),
+                            ptr: Cell::new(NonNull::new_unchecked(child_footer as *mut u8)),
+                        },
+                    );
+                    // Reserve all of the parent's free space for the child.
+                    footer.ptr.set(footer.data);
+                    (
+                        NonNull::new_unchecked(child_footer),
+                        Some((parent_footer, ptr)),
+                    )
+                }
+                None => (
+                    Bump::new_chunk(None, None, parent.chunk_align(), None)?,
+                    None,
+                ),
+            };
+
+            Some(ChildBump {
+                bump: ManuallyDrop::new(Bump {
+                    current_chunk_footer: Cell::new(current_chunk_footer),
+                    allocated_objects: Cell::new(0),
+                    large_slice_threshold: parent.large_slice_threshold,
+                    chunk_align_shift: parent.chunk_align_shift,
+                    large_slice_align_shift: parent.large_slice_align_shift,
+                }),
+                borrowed,
+                parent,
+            })
+        }
+    }
+
+    /// Returns the arena that this child was forked from.
+    pub fn parent(&self) -> &'parent Bump {
+        self.parent
+    }
+}
+
+impl<'parent> Deref for ChildBump<'parent> {
+    type Target = Bump;
+
+    fn deref(&self) -> &Bump {
+        &self.bump
+    }
+}
+
+impl<'parent> Drop for ChildBump<'parent> {
+    fn drop(&mut self) {
+        unsafe {
+            let mut footer = Some(self.bump.current_chunk_footer.get());
+            while let Some(f) = footer {
+                footer = f.as_ref().prev.get();
+                if footer.is_none() && self.borrowed.is_some() {
+                    // The borrowed space belongs to the parent's chunk.
+                    break;
+                }
+                dealloc(f.as_ref().data.as_ptr(), f.as_ref().layout);
+            }
+
+            if let Some((parent_footer, parent_ptr)) = self.borrowed {
+                // Give the space back, unless the parent has allocated since
+                // the fork, in which case it stays reserved until the parent
+                // is reset.
+                let pf = parent_footer.as_ref();
+                if self.parent.current_chunk_footer.get() == parent_footer
+                    && pf.ptr.get() == pf.data
+                {
+                    pf.ptr.set(parent_ptr);
+                }
+            }
+        }
+    }
+}
+
+impl<'parent> fmt::Debug for ChildBump<'parent> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChildBump")
+            .field("bump", &*self.bump)
+            .field("borrowed", &self.borrowed.is_some())
+            .finish()
+    }
+}
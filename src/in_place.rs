@@ -0,0 +1,99 @@
+use crate::Bump;
+use core::alloc::Layout;
+use core::mem::MaybeUninit;
+
+/// Allocates a struct in a `Bump` and initializes it one field at a time,
+/// directly in the arena.
+///
+/// Unlike [`Bump::alloc_with`](./struct.Bump.html#method.alloc_with), which
+/// relies on the optimizer to elide the temporary that holds the closure's
+/// result, this never materializes the whole struct anywhere but in its arena
+/// slot: each field's value is written into place as soon as it has been
+/// evaluated, and the slot itself is reserved by layout rather than by
+/// moving an uninitialized value into the arena. This makes it suitable for
+/// structs too large to build on the stack.
+///
+/// With optimizations, the most stack space needed is typically that of the
+/// largest single field. Without optimizations, the compiler gives every
+/// field's value its own stack slots, and may copy a value more than once
+/// before it is written, so a debug build can need a few times the struct's
+/// size in stack space before counting anything else. What the macro does
+/// guarantee in every build is that no copy of the whole struct is made on
+/// the stack on top of that.
+///
+/// The syntax is that of a struct literal with named fields, preceded by the
+/// `&Bump` to allocate in. Every field must be given exactly once, as in a
+/// regular struct literal; `..base` is not supported. Fields are evaluated and
+/// written in the order they are listed. If evaluating a field panics, the
+/// fields written so far are leaked.
+///
+/// Returns an exclusive reference to the initialized struct.
+///
+/// ## Example
+///
+/// ```
+/// use bumpalo::{alloc_in_place, Bump};
+///
+/// struct Node {
+///     id: u32,
+///     keys: [u64; 4096],
+///     values: [u64; 4096],
+/// }
+///
+/// let bump = Bump::new();
+/// let node = alloc_in_place!(in &bump; Node {
+///     id: 7,
+///     keys: [1; 4096],
+///     values: [2; 4096],
+/// });
+///
+/// assert_eq!(node.id, 7);
+/// assert!(node.keys.iter().all(|&k| k == 1));
+/// assert!(node.values.iter().all(|&v| v == 2));
+/// ```
+#[macro_export]
+macro_rules! alloc_in_place {
+    (
+        in $bump:expr;
+        $name:ident $(:: $path:ident)* $(< $($generic:ty),+ >)? {
+            $($field:ident : $value:expr),* $(,)?
+        }
+    ) => {{
+        // Fails to compile unless every field is given exactly once. Never
+        // evaluated.
+        if false {
+            let _ = $name $(:: $path)* $(::< $($generic),+ >)? {
+                $($field: $crate::__in_place_unreachable()),*
+            };
+        }
+        let slot = $crate::__in_place_uninit_slot::<$name $(:: $path)* $(< $($generic),+ >)?>($bump);
+        let ptr = slot.as_mut_ptr();
+        $(
+            let value = $value;
+            unsafe { ::core::ptr::addr_of_mut!((*ptr).$field).write_unaligned(value) };
+        )*
+        unsafe { $crate::__in_place_assume_init(slot) }
+    }};
+}
+
+// Reserves the slot by layout, so that unlike `Bump::alloc_with`, no
+// `MaybeUninit<T>` is ever built on the stack and moved into it.
+#[doc(hidden)]
+#[inline(always)]
+#[allow(clippy::mut_from_ref)]
+pub fn uninit_slot<T>(bump: &Bump) -> &mut MaybeUninit<T> {
+    let ptr = bump
+        .alloc_layout(Layout::new::<T>())
+        .cast::<MaybeUninit<T>>();
+    unsafe { &mut *ptr.as_ptr() }
+}
+
+#[doc(hidden)]
+pub fn unreachable<T>() -> T {
+    unreachable!()
+}
+
+#[doc(hidden)]
+pub unsafe fn assume_init<T>(slot: &mut MaybeUninit<T>) -> &mut T {
+    &mut *slot.as_mut_ptr()
+}
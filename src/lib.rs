@@ -148,10 +148,20 @@ assert_eq!(NUM_DROPPED.load(Ordering::SeqCst), 1);
 # }
 ```
 
+With the unstable, nightly-only `"coerce_unsized"` cargo feature also enabled,
+a `Box<T>` coerces to a `Box<dyn Trait>` (or a `Box<[T]>`) the same way that
+`std::boxed::Box` does, e.g. `let b: Box<dyn Display> = Box::new_in(5, &bump);`.
+Like `"allocator_api"`, this feature does not follow the crate's semver
+conventions.
+
 ## `#![no_std]` Support
 
 Bumpalo is a `no_std` crate. It depends only on the `alloc` and `core` crates.
 
+The optional `"std"` cargo feature enables the few methods that need `std`,
+such as `Bump::write_chunks_to` and `Bump::read_chunks_from` for persisting an
+arena's chunks with `std::io`.
+
 ## Thread support
 
 The `Bump` is `!Send`, which makes it hard to use in certain situations around threads ‒ for
@@ -207,9 +217,30 @@ v.push(2);
 # }
 ```
 
+A collection can also own its `Bump`, which is handy for returning a
+self-contained collection without separately returning the arena that backs
+it:
+
+```
+# #![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+# #[cfg(feature = "allocator_api")]
+# {
+#![feature(allocator_api)]
+use bumpalo::Bump;
+
+fn squares(n: u64) -> Vec<u64, Bump> {
+    let mut v = Vec::new_in(Bump::new());
+    v.extend((0..n).map(|i| i * i));
+    v
+}
+
+assert_eq!(squares(4), [0, 1, 4, 9]);
+# }
+```
+
 ### Minimum Supported Rust Version (MSRV)
 
-This crate is guaranteed to compile on stable Rust 1.44 and up. It might compile
+This crate is guaranteed to compile on stable Rust 1.51 and up. It might compile
 with older versions but that may change in any new patch release.
 
 We reserve the right to increment the MSRV on minor releases, however we will strive
@@ -224,19 +255,44 @@ to only do it deliberately and for good reasons.
     feature = "allocator_api",
     feature(allocator_api, nonnull_slice_from_raw_parts)
 )]
+#![cfg_attr(
+    all(feature = "boxed", feature = "coerce_unsized"),
+    feature(coerce_unsized, unsize)
+)]
 
 #[doc(hidden)]
 pub extern crate alloc as core_alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
 #[cfg(feature = "boxed")]
 pub mod boxed;
 #[cfg(feature = "collections")]
 pub mod collections;
 
+mod aligned;
 mod alloc;
+mod fork;
+mod in_place;
+mod pool;
+mod segmented;
+mod writer;
+
+pub use crate::aligned::Aligned;
+pub use crate::fork::ChildBump;
+#[doc(hidden)]
+pub use crate::in_place::{
+    assume_init as __in_place_assume_init, uninit_slot as __in_place_uninit_slot,
+    unreachable as __in_place_unreachable,
+};
+pub use crate::pool::{ChunkPool, PooledBump};
+pub use crate::segmented::{SegmentedSlice, SegmentedSliceIter};
+pub use crate::writer::ArenaWriter;
 
 use core::cell::Cell;
-use core::fmt::Display;
+use core::cmp;
+use core::fmt::{self, Display};
 use core::iter;
 use core::marker::PhantomData;
 use core::mem;
@@ -246,6 +302,10 @@ use core::str;
 use core_alloc::alloc::{alloc, dealloc, Layout};
 #[cfg(feature = "allocator_api")]
 use core_alloc::alloc::{AllocError, Allocator};
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "std")]
+use std::os::raw::c_char;
 
 /// An error returned from [`Bump::try_alloc_try_with`].
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -273,6 +333,35 @@ impl<E: Display> Display for AllocOrInitError<E> {
     }
 }
 
+/// An error returned from the fallible slice allocation methods, such as
+/// [`Bump::try_alloc_slice_fill_copy`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum SliceAllocError {
+    /// Indicates that the slice's size in bytes would overflow `isize`, so no
+    /// allocation could ever hold it.
+    LayoutOverflow,
+    /// Indicates that reserving space for the slice failed.
+    OutOfMemory,
+}
+impl From<alloc::AllocErr> for SliceAllocError {
+    fn from(_: alloc::AllocErr) -> Self {
+        Self::OutOfMemory
+    }
+}
+impl From<SliceAllocError> for alloc::AllocErr {
+    fn from(_: SliceAllocError) -> Self {
+        alloc::AllocErr
+    }
+}
+impl Display for SliceAllocError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SliceAllocError::LayoutOverflow => f.write_str("slice size overflows `isize`"),
+            SliceAllocError::OutOfMemory => alloc::AllocErr.fmt(f),
+        }
+    }
+}
+
 /// An arena to bump allocate into.
 ///
 /// ## No `Drop`s
@@ -394,6 +483,23 @@ impl<E: Display> Display for AllocOrInitError<E> {
 /// }
 /// ```
 ///
+/// The `try_alloc_slice_` methods report failure with a
+/// [`SliceAllocError`](./enum.SliceAllocError.html), which tells a slice too
+/// big to describe at all, because its size in bytes would overflow `isize`,
+/// apart from running out of memory. That way, for example, a shorter slice
+/// can be retried only when memory really ran out.
+///
+/// ```
+/// use bumpalo::SliceAllocError;
+///
+/// let bump = bumpalo::Bump::new();
+///
+/// assert_eq!(
+///     bump.try_alloc_slice_fill_copy(usize::MAX / 2, 0u64),
+///     Err(SliceAllocError::LayoutOverflow),
+/// );
+/// ```
+///
 /// ### Initializer Functions: The `_with` Method Suffix
 ///
 /// Calling one of the generic `…alloc(x)` methods is essentially equivalent to
@@ -422,6 +528,14 @@ impl<E: Display> Display for AllocOrInitError<E> {
 /// the value is constructed on the heap. To the best of our knowledge no such
 /// guarantee can be made in stable Rust as of 1.44.
 ///
+/// When a struct must not be built on the stack, for example because it is
+/// larger than the stack itself, use the [`alloc_in_place!`](macro.alloc_in_place.html)
+/// macro instead. It writes each field directly into the arena, so the whole
+/// struct is never held on the stack as a whole. With optimizations, usually
+/// only one field's value is; without them, every field's value may be, some
+/// more than once, so debug builds still need plenty of stack for very large
+/// structs.
+///
 /// ### Fallible Initialization: The `_try_with` Method Suffix
 ///
 /// The generic [`…alloc_try_with(|| x)`](?search=_try_with) methods behave
@@ -430,6 +544,14 @@ impl<E: Display> Display for AllocOrInitError<E> {
 /// [`Result`] and will attempt to undo the initial allocation if this closure
 /// returns [`Err`].
 ///
+/// As long as the closure leaves no allocations of its own in `self`, undoing
+/// the allocation always succeeds: after an [`Err`], `allocated_bytes()`
+/// returns exactly what it did before the call, alignment padding included.
+/// This holds even for a `T` too large for the current chunk. The new chunk
+/// that was allocated for it is kept, empty, for later allocations, so calling
+/// a failing constructor in a retry loop does not use up any more of the arena
+/// on each attempt.
+///
 /// #### Warning
 ///
 /// If the inner closure returns [`Ok`], space for the entire [`Result`] remains
@@ -462,10 +584,26 @@ impl<E: Display> Display for AllocOrInitError<E> {
 /// stack, `bump.…alloc_try_with(|| x)?` is likely to execute more slowly than
 /// the matching `bump.…alloc(x?)` in case of initialization failure. If this
 /// happens frequently, using the plain un-suffixed method may perform better.
-#[derive(Debug)]
 pub struct Bump {
     // The current chunk we are bump allocating within.
     current_chunk_footer: Cell<NonNull<ChunkFooter>>,
+
+    // The number of allocations made since creation or the last reset.
+    allocated_objects: Cell<usize>,
+
+    // `alloc_slice_copy` and `alloc_slice_fill_copy` allocations of at least
+    // `large_slice_threshold` bytes are aligned to at least
+    // `1 << large_slice_align_shift`. Disabled by default with a threshold of
+    // `usize::MAX`.
+    large_slice_threshold: usize,
+
+    // The alignments are powers of two, so only their base-2 logarithms are
+    // stored, which keeps them both within a single word.
+    //
+    // The minimum alignment of every chunk this arena allocates is
+    // `1 << chunk_align_shift`, which is at least `CHUNK_ALIGN`.
+    chunk_align_shift: u8,
+    large_slice_align_shift: u8,
 }
 
 #[repr(C)]
@@ -499,12 +637,41 @@ impl Drop for Bump {
     }
 }
 
+/// Prints a summary of the arena's memory rather than its contents: the number
+/// of chunks, their total capacity, how many of those bytes are in use, and how
+/// many are still free in the current chunk.
+impl fmt::Debug for Bump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (mut chunks, mut capacity, mut used) = (0, 0, 0);
+        for (chunk_capacity, chunk_used) in self.chunk_usage() {
+            chunks += 1;
+            capacity += chunk_capacity;
+            used += chunk_used;
+        }
+        let footer = self.current_chunk_footer.get();
+        let footer = unsafe { footer.as_ref() };
+        let remaining = footer.ptr.get().as_ptr() as usize - footer.data.as_ptr() as usize;
+
+        f.debug_struct("Bump")
+            .field("chunks", &chunks)
+            .field("capacity", &capacity)
+            .field("used", &used)
+            .field("remaining", &remaining)
+            .finish()
+    }
+}
+
+/// Deallocates the given chunk and all the chunks before it, returning the
+/// number of bytes that were allocated in them.
 #[inline]
-unsafe fn dealloc_chunk_list(mut footer: Option<NonNull<ChunkFooter>>) {
+unsafe fn dealloc_chunk_list(mut footer: Option<NonNull<ChunkFooter>>) -> usize {
+    let mut used = 0;
     while let Some(f) = footer {
+        used += f.as_ptr() as usize - f.as_ref().ptr.get().as_ptr() as usize;
         footer = f.as_ref().prev.get();
         dealloc(f.as_ref().data.as_ptr(), f.as_ref().layout);
     }
+    used
 }
 
 // `Bump`s are safe to send between threads because nothing aliases its owned
@@ -520,8 +687,12 @@ pub(crate) fn round_up_to(n: usize, divisor: usize) -> Option<usize> {
     Some(n.checked_add(divisor - 1)? & !(divisor - 1))
 }
 
+// The page size assumed when sizing chunks. It is the page size on most
+// systems, and there is no portable way to ask for the real one in `no_std`.
+const PAGE_SIZE: usize = 0x1000;
+
 // After this point, we try to hit page boundaries instead of powers of 2
-const PAGE_STRATEGY_CUTOFF: usize = 0x1000;
+const PAGE_STRATEGY_CUTOFF: usize = PAGE_SIZE;
 
 // We only support alignments of up to 16 bytes for iter_allocated_chunks.
 const SUPPORTED_ITER_ALIGNMENT: usize = 16;
@@ -532,6 +703,10 @@ const FOOTER_SIZE: usize = mem::size_of::<ChunkFooter>();
 const _FOOTER_ALIGN_ASSERTION: bool = mem::align_of::<ChunkFooter>() <= CHUNK_ALIGN;
 const _: [(); _FOOTER_ALIGN_ASSERTION as usize] = [()];
 
+// Allocations whose size and alignment are both at most this many bytes take
+// the single-comparison fast path in `try_alloc_layout_fast`.
+const SMALL_OBJECT_MAX: usize = 32;
+
 // Maximum typical overhead per allocation imposed by allocators.
 const MALLOC_OVERHEAD: usize = 16;
 
@@ -563,6 +738,16 @@ unsafe fn layout_from_size_align(size: usize, align: usize) -> Layout {
     }
 }
 
+/// `Layout::array`, but enforcing the `isize::MAX` limit on its size that
+/// older versions of `Layout` don't.
+#[inline]
+fn slice_layout<T>(len: usize) -> Result<Layout, SliceAllocError> {
+    match Layout::array::<T>(len) {
+        Ok(layout) if layout.size() <= isize::MAX as usize => Ok(layout),
+        _ => Err(SliceAllocError::LayoutOverflow),
+    }
+}
+
 #[inline(never)]
 fn allocation_size_overflow<T>() -> T {
     panic!("requested allocation size overflowed")
@@ -614,15 +799,206 @@ impl Bump {
     /// # let _ = bump.unwrap();
     /// ```
     pub fn try_with_capacity(capacity: usize) -> Result<Self, alloc::AllocErr> {
+        Bump::try_with_capacity_and_chunk_alignment(capacity, CHUNK_ALIGN)
+    }
+
+    /// Construct a new arena whose first chunk has room for exactly
+    /// `capacity` bytes, rounded up to a multiple of the page size.
+    ///
+    /// `with_capacity` rounds its chunk up to a size that wastes little memory
+    /// in the global allocator, so the capacity you get depends on the
+    /// allocator's overhead. This constructor instead makes the first chunk's
+    /// [`chunk_capacity`](#method.chunk_capacity) a whole number of pages, at
+    /// least one, so it can be predicted exactly: as long as the allocations
+    /// fit in that capacity, `allocated_bytes()` never exceeds it. The price
+    /// is that the chunk itself, with its footer, is slightly larger than a
+    /// whole number of pages.
+    ///
+    /// The page size is taken to be 4 KiB, which it is on most systems. Later
+    /// chunks, allocated once the first one is full, are sized as usual.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if allocating the initial chunk fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::with_capacity_page_rounded(5000);
+    /// assert_eq!(bump.chunk_capacity(), 8192);
+    ///
+    /// // The whole capacity can be allocated without a new chunk.
+    /// bump.alloc_slice_fill_copy(8192, 0u8);
+    /// assert_eq!(bump.allocated_bytes(), 8192);
+    /// assert_eq!(bump.chunk_capacity(), 8192);
+    /// ```
+    pub fn with_capacity_page_rounded(capacity: usize) -> Bump {
+        Bump::try_with_capacity_page_rounded(capacity).unwrap_or_else(|_| oom())
+    }
+
+    /// Attempt to construct a new arena whose first chunk has room for exactly
+    /// `capacity` bytes, rounded up to a multiple of the page size.
+    ///
+    /// See [`with_capacity_page_rounded`](#method.with_capacity_page_rounded)
+    /// for details.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if allocating the initial chunk fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::try_with_capacity_page_rounded(100).unwrap();
+    /// assert_eq!(bump.chunk_capacity(), 4096);
+    /// ```
+    pub fn try_with_capacity_page_rounded(capacity: usize) -> Result<Self, alloc::AllocErr> {
+        let capacity = round_up_to(capacity.max(1), PAGE_SIZE).ok_or(alloc::AllocErr)?;
+        if Layout::from_size_align(capacity.saturating_add(FOOTER_SIZE), CHUNK_ALIGN).is_err() {
+            return Err(alloc::AllocErr);
+        }
+
+        let chunk_footer =
+            Self::new_chunk_exact(capacity, CHUNK_ALIGN, None).ok_or(alloc::AllocErr {})?;
+
+        Ok(Bump {
+            current_chunk_footer: Cell::new(chunk_footer),
+            allocated_objects: Cell::new(0),
+            large_slice_threshold: usize::MAX,
+            chunk_align_shift: CHUNK_ALIGN.trailing_zeros() as u8,
+            large_slice_align_shift: 0,
+        })
+    }
+
+    /// Construct a new arena whose chunks all start at an address that is a
+    /// multiple of `align`.
+    ///
+    /// This is useful when the chunks' memory needs to be, for example, page
+    /// aligned. Alignments smaller than the default chunk alignment of 16
+    /// bytes have no effect. Allocating a chunk with a large alignment may be
+    /// slower and waste more memory in the global allocator, but it does not
+    /// change how much of each chunk is available for bump allocation.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `align` is not a power of two, or if allocating the initial
+    /// chunk fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::with_chunk_alignment(4096);
+    ///
+    /// // An allocation that fills the whole chunk starts at its base.
+    /// let whole_chunk = bump.alloc_slice_fill_copy(bump.chunk_capacity(), 0u8);
+    /// assert_eq!(whole_chunk.as_ptr() as usize % 4096, 0);
+    /// ```
+    pub fn with_chunk_alignment(align: usize) -> Bump {
+        assert!(align.is_power_of_two(), "alignment must be a power of two");
+        Bump::try_with_chunk_alignment(align).unwrap_or_else(|_| oom())
+    }
+
+    /// Attempt to construct a new arena whose chunks all start at an address
+    /// that is a multiple of `align`.
+    ///
+    /// See [`with_chunk_alignment`](#method.with_chunk_alignment) for details.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if `align` is not a power of two, or if allocating the initial
+    /// chunk fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::try_with_chunk_alignment(4096);
+    /// # let _ = bump.unwrap();
+    /// assert!(bumpalo::Bump::try_with_chunk_alignment(100).is_err());
+    /// ```
+    pub fn try_with_chunk_alignment(align: usize) -> Result<Bump, alloc::AllocErr> {
+        if Layout::from_size_align(FIRST_ALLOCATION_GOAL, align).is_err() {
+            return Err(alloc::AllocErr);
+        }
+        Bump::try_with_capacity_and_chunk_alignment(0, align.max(CHUNK_ALIGN))
+    }
+
+    /// Over-align large slices copied into this arena.
+    ///
+    /// After calling this method, every
+    /// [`alloc_slice_copy`](#method.alloc_slice_copy),
+    /// [`alloc_slice_fill_copy`](#method.alloc_slice_fill_copy),
+    /// [`alloc_bytes`](#method.alloc_bytes), and
+    /// [`alloc_zeroed_bytes`](#method.alloc_zeroed_bytes) allocation
+    /// (and their `try_` counterparts) whose size is at least `min_size` bytes
+    /// is aligned to at least `align` bytes. Smaller allocations are not
+    /// affected, and so don't pay for the extra padding.
+    ///
+    /// Aligning large copies to the SIMD register width, for example 32
+    /// bytes, can speed up bulk copies into and out of them.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut bump = bumpalo::Bump::new();
+    /// bump.set_large_slice_alignment(32, 256);
+    ///
+    /// let large = bump.alloc_slice_fill_copy(1000, 0u8);
+    /// assert_eq!(large.as_ptr() as usize % 32, 0);
+    /// ```
+    pub fn set_large_slice_alignment(&mut self, align: usize, min_size: usize) {
+        assert!(
+            Layout::from_size_align(0, align).is_ok(),
+            "alignment must be a power of two"
+        );
+        self.large_slice_align_shift = align.trailing_zeros() as u8;
+        self.large_slice_threshold = min_size;
+    }
+
+    /// The minimum alignment of every chunk this arena allocates.
+    #[inline]
+    fn chunk_align(&self) -> usize {
+        1 << self.chunk_align_shift
+    }
+
+    /// Applies the `set_large_slice_alignment` option to a slice's layout.
+    #[inline(always)]
+    fn large_slice_layout(&self, layout: Layout) -> Layout {
+        if layout.size() >= self.large_slice_threshold {
+            // Only fails if the padded size would overflow, in which case the
+            // allocation itself would fail anyways.
+            layout
+                .align_to(1 << self.large_slice_align_shift)
+                .unwrap_or(layout)
+        } else {
+            layout
+        }
+    }
+
+    fn try_with_capacity_and_chunk_alignment(
+        capacity: usize,
+        chunk_align: usize,
+    ) -> Result<Bump, alloc::AllocErr> {
+        debug_assert!(chunk_align.is_power_of_two());
+        debug_assert!(chunk_align >= CHUNK_ALIGN);
+
         let chunk_footer = Self::new_chunk(
             None,
             Some(unsafe { layout_from_size_align(capacity, 1) }),
+            chunk_align,
             None,
         )
         .ok_or(alloc::AllocErr {})?;
 
         Ok(Bump {
             current_chunk_footer: Cell::new(chunk_footer),
+            allocated_objects: Cell::new(0),
+            large_slice_threshold: usize::MAX,
+            chunk_align_shift: chunk_align.trailing_zeros() as u8,
+            large_slice_align_shift: 0,
         })
     }
 
@@ -631,42 +1007,64 @@ impl Bump {
     /// If given, `layouts` is a tuple of the current chunk size and the
     /// layout of the allocation request that triggered us to fall back to
     /// allocating a new chunk of memory.
+    ///
+    /// The chunk is aligned to at least `chunk_align`, which must be a power of
+    /// two that is at least `CHUNK_ALIGN`.
     fn new_chunk(
         new_size_without_footer: Option<usize>,
         requested_layout: Option<Layout>,
+        chunk_align: usize,
         prev: Option<NonNull<ChunkFooter>>,
     ) -> Option<NonNull<ChunkFooter>> {
-        unsafe {
-            let mut new_size_without_footer =
-                new_size_without_footer.unwrap_or(DEFAULT_CHUNK_SIZE_WITHOUT_FOOTER);
-
-            // We want to have CHUNK_ALIGN or better alignment
-            let mut align = CHUNK_ALIGN;
-
-            // If we already know we need to fulfill some request,
-            // make sure we allocate at least enough to satisfy it
-            if let Some(requested_layout) = requested_layout {
-                align = align.max(requested_layout.align());
-                let requested_size = round_up_to(requested_layout.size(), align)
-                    .unwrap_or_else(allocation_size_overflow);
-                new_size_without_footer = new_size_without_footer.max(requested_size);
-            }
+        let mut new_size_without_footer =
+            new_size_without_footer.unwrap_or(DEFAULT_CHUNK_SIZE_WITHOUT_FOOTER);
 
-            // We want our allocations to play nice with the memory allocator,
-            // and waste as little memory as possible.
-            // For small allocations, this means that the entire allocation
-            // including the chunk footer and mallocs internal overhead is
-            // as close to a power of two as we can go without going over.
-            // For larger allocations, we only need to get close to a page
-            // boundary without going over.
-            if new_size_without_footer < PAGE_STRATEGY_CUTOFF {
-                new_size_without_footer =
-                    (new_size_without_footer + OVERHEAD).next_power_of_two() - OVERHEAD;
-            } else {
-                new_size_without_footer =
-                    round_up_to(new_size_without_footer + OVERHEAD, 0x1000)? - OVERHEAD;
-            }
+        // We want to have `chunk_align` or better alignment
+        let mut align = chunk_align;
+
+        // If we already know we need to fulfill some request,
+        // make sure we allocate at least enough to satisfy it
+        if let Some(requested_layout) = requested_layout {
+            align = align.max(requested_layout.align());
+            let requested_size = round_up_to(requested_layout.size(), align)
+                .unwrap_or_else(allocation_size_overflow);
+            new_size_without_footer = new_size_without_footer.max(requested_size);
+        }
+
+        // We want our allocations to play nice with the memory allocator,
+        // and waste as little memory as possible.
+        // For small allocations, this means that the entire allocation
+        // including the chunk footer and mallocs internal overhead is
+        // as close to a power of two as we can go without going over.
+        // For larger allocations, we only need to get close to a page
+        // boundary without going over.
+        if new_size_without_footer < PAGE_STRATEGY_CUTOFF {
+            new_size_without_footer =
+                (new_size_without_footer + OVERHEAD).next_power_of_two() - OVERHEAD;
+        } else {
+            new_size_without_footer =
+                round_up_to(new_size_without_footer + OVERHEAD, PAGE_SIZE)? - OVERHEAD;
+        }
 
+        debug_assert!(requested_layout.map_or(true, |layout| {
+            new_size_without_footer + FOOTER_SIZE >= layout.size()
+        }));
+
+        Bump::new_chunk_exact(new_size_without_footer, align, prev)
+    }
+
+    /// Allocates a new chunk with exactly `new_size_without_footer` bytes to
+    /// bump allocate into, without rounding to a size that suits the global
+    /// allocator.
+    ///
+    /// `new_size_without_footer` must be a multiple of `CHUNK_ALIGN`, and
+    /// `align` a power of two that is at least `CHUNK_ALIGN`.
+    fn new_chunk_exact(
+        new_size_without_footer: usize,
+        align: usize,
+        prev: Option<NonNull<ChunkFooter>>,
+    ) -> Option<NonNull<ChunkFooter>> {
+        unsafe {
             debug_assert_eq!(align % CHUNK_ALIGN, 0);
             debug_assert_eq!(new_size_without_footer % CHUNK_ALIGN, 0);
             let size = new_size_without_footer
@@ -674,8 +1072,6 @@ impl Bump {
                 .unwrap_or_else(allocation_size_overflow);
             let layout = layout_from_size_align(size, align);
 
-            debug_assert!(requested_layout.map_or(true, |layout| size >= layout.size()));
-
             let data = alloc(layout);
             let data = NonNull::new(data)?;
 
@@ -712,7 +1108,9 @@ impl Bump {
     /// documentation](./struct.Bump.html) for details.
     ///
     /// If this arena has allocated multiple chunks to bump allocate into, then
-    /// the excess chunks are returned to the global allocator.
+    /// the excess chunks are returned to the global allocator. Otherwise,
+    /// resetting only rewinds the bump pointer, which makes it cheap enough to
+    /// call on every iteration of a hot loop.
     ///
     /// ## Example
     ///
@@ -736,18 +1134,54 @@ impl Bump {
     /// }
     ///```
     pub fn reset(&mut self) {
+        self.reset_returning_used();
+    }
+
+    /// Reset this bump allocator, like [`reset`](#method.reset), and return
+    /// the number of bytes that were allocated in it just before the reset.
+    ///
+    /// The returned count is the same as what
+    /// [`allocated_bytes`](#method.allocated_bytes) would have returned right
+    /// before resetting, but it is computed while resetting instead of with a
+    /// separate walk over the arena's chunks.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut bump = bumpalo::Bump::new();
+    /// bump.alloc_slice_fill_copy(100, 0u8);
+    ///
+    /// assert_eq!(bump.reset_returning_used(), 100);
+    /// assert_eq!(bump.reset_returning_used(), 0);
+    /// ```
+    pub fn reset_returning_used(&mut self) -> usize {
         // Takes `&mut self` so `self` must be unique and there can't be any
         // borrows active that would get invalidated by resetting.
         unsafe {
             let cur_chunk = self.current_chunk_footer.get();
 
+            // An arena that is already clean, for example one reset on every
+            // iteration of a loop that did not allocate this time, is left
+            // alone without writing to its footer or counters.
+            if cur_chunk.as_ref().prev.get().is_none()
+                && cur_chunk.as_ref().ptr.get() == cur_chunk.cast()
+                && self.allocated_objects.get() == 0
+            {
+                return 0;
+            }
+
+            let mut used =
+                cur_chunk.as_ptr() as usize - cur_chunk.as_ref().ptr.get().as_ptr() as usize;
+
             // Deallocate all chunks except the current one
             let prev_chunk = cur_chunk.as_ref().prev.replace(None);
-            dealloc_chunk_list(prev_chunk);
+            used += dealloc_chunk_list(prev_chunk);
 
             // Reset the bump finger to the end of the chunk.
             cur_chunk.as_ref().ptr.set(cur_chunk.cast());
 
+            self.allocated_objects.set(0);
+
             debug_assert!(
                 self.current_chunk_footer
                     .get()
@@ -762,9 +1196,117 @@ impl Bump {
                 self.current_chunk_footer.get().cast(),
                 "Our chunk's bump finger should be reset to the start of its allocation"
             );
+
+            used
         }
     }
 
+    /// Asserts that this arena is in the state that `reset` leaves it in: a
+    /// single chunk, with nothing allocated in it.
+    ///
+    /// This is meant for tests of code that uses an arena and is supposed to
+    /// leave it clean, such as code that resets an arena and hands it back for
+    /// reuse. Calling this at the point where the arena should be clean gives a
+    /// precise failure if anything was allocated in it after the reset,
+    /// including zero-sized values, which don't take up any bytes.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the arena has more than one chunk, if any bytes are allocated
+    /// in its chunk, or if any allocations were made since it was created or
+    /// last reset.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut bump = bumpalo::Bump::new();
+    /// bump.assert_reset_clean();
+    ///
+    /// bump.alloc_slice_fill_copy(100_000, 0u8);
+    /// bump.reset();
+    /// bump.assert_reset_clean();
+    /// ```
+    ///
+    /// ```should_panic
+    /// let mut bump = bumpalo::Bump::new();
+    /// bump.reset();
+    /// bump.alloc(1u8);
+    ///
+    /// // Panics: something was allocated after the reset.
+    /// bump.assert_reset_clean();
+    /// ```
+    #[track_caller]
+    pub fn assert_reset_clean(&self) {
+        let footer = self.current_chunk_footer.get();
+        let footer = unsafe { footer.as_ref() };
+        assert!(
+            footer.prev.get().is_none(),
+            "arena is not clean: it has {} chunks, but a reset arena has only one",
+            self.iter_allocated_chunks_raw().count()
+        );
+        let used = footer as *const _ as usize - footer.ptr.get().as_ptr() as usize;
+        assert!(
+            used == 0,
+            "arena is not clean: {} bytes are allocated in it",
+            used
+        );
+        assert!(
+            self.allocated_objects.get() == 0,
+            "arena is not clean: {} allocations were made since it was reset",
+            self.allocated_objects.get()
+        );
+    }
+
+    /// Creates a child arena whose allocations are all thrown away when it is
+    /// dropped, for scoped garbage inside a longer-lived arena.
+    ///
+    /// The child first allocates from the free space left in this arena's
+    /// current chunk, without touching the global allocator, and then grows
+    /// with chunks of its own. Its allocations borrow the child, so they
+    /// can't outlive it. When the child is dropped, its own chunks are freed,
+    /// and the free space it borrowed is handed back to this arena, as long as
+    /// this arena hasn't allocated anything in the meantime. Otherwise that
+    /// space stays reserved until this arena is reset.
+    ///
+    /// See [`ChildBump`](./struct.ChildBump.html) for more details.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the child needs a chunk of its own to start with and
+    /// allocating it fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let result = bump.alloc(0u64);
+    ///
+    /// let before = bump.allocated_bytes();
+    /// {
+    ///     let scratch = bump.fork();
+    ///     let garbage = scratch.alloc_slice_fill_copy(16, 1u64);
+    ///     *result = garbage.iter().sum();
+    /// }
+    ///
+    /// // The garbage is gone, and the arena can reuse its space.
+    /// assert_eq!(*result, 16);
+    /// assert_eq!(bump.allocated_bytes(), before);
+    /// ```
+    pub fn fork(&self) -> ChildBump<'_> {
+        self.try_fork().unwrap_or_else(|_| oom())
+    }
+
+    /// Like `fork`, but returns an `Err` instead of panicking if the child
+    /// needs a chunk of its own and allocating it fails.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if the child needs a chunk of its own to start with and
+    /// allocating it fails.
+    pub fn try_fork(&self) -> Result<ChildBump<'_>, alloc::AllocErr> {
+        ChildBump::try_new(self).ok_or(alloc::AllocErr)
+    }
+
     /// Allocate an object in this `Bump` and return an exclusive reference to
     /// it.
     ///
@@ -805,70 +1347,208 @@ impl Bump {
         self.try_alloc_with(|| val)
     }
 
-    /// Pre-allocate space for an object in this `Bump`, initializes it using
-    /// the closure, then returns an exclusive reference to it.
+    /// Allocate an object in this `Bump`, aligned to at least `align` bytes,
+    /// and return an exclusive reference to it.
     ///
-    /// See [The `_with` Method Suffix](#the-_with-method-suffix) for a
-    /// discussion on the differences between the `_with` suffixed methods and
-    /// those methods without it, their performance characteristics, and when
-    /// you might or might not choose a `_with` suffixed method.
+    /// The object is aligned to the larger of `align` and `T`'s own
+    /// alignment, so an `align` smaller than `T`'s alignment has no effect.
     ///
     /// ## Panics
     ///
-    /// Panics if reserving space for `T` fails.
+    /// Panics if `align` is not a power of two, or if reserving space for `T`
+    /// fails.
     ///
     /// ## Example
     ///
     /// ```
     /// let bump = bumpalo::Bump::new();
-    /// let x = bump.alloc_with(|| "hello");
-    /// assert_eq!(*x, "hello");
+    /// let x = bump.alloc_with_align([0u8; 3], 64);
+    /// assert_eq!(x as *mut _ as usize % 64, 0);
+    /// assert_eq!(*x, [0, 0, 0]);
     /// ```
     #[inline(always)]
     #[allow(clippy::mut_from_ref)]
-    pub fn alloc_with<F, T>(&self, f: F) -> &mut T
-    where
-        F: FnOnce() -> T,
-    {
-        #[inline(always)]
-        unsafe fn inner_writer<T, F>(ptr: *mut T, f: F)
-        where
-            F: FnOnce() -> T,
-        {
-            // This function is translated as:
-            // - allocate space for a T on the stack
-            // - call f() with the return value being put onto this stack space
-            // - memcpy from the stack to the heap
-            //
-            // Ideally we want LLVM to always realize that doing a stack
-            // allocation is unnecessary and optimize the code so it writes
-            // directly into the heap instead. It seems we get it to realize
-            // this most consistently if we put this critical line into it's
-            // own function instead of inlining it into the surrounding code.
-            ptr::write(ptr, f())
-        }
-
-        let layout = Layout::new::<T>();
-
-        unsafe {
-            let p = self.alloc_layout(layout);
-            let p = p.as_ptr() as *mut T;
-            inner_writer(p, f);
-            &mut *p
-        }
+    pub fn alloc_with_align<T>(&self, val: T, align: usize) -> &mut T {
+        assert!(align.is_power_of_two(), "alignment must be a power of two");
+        self.try_alloc_with_align(val, align)
+            .unwrap_or_else(|_| oom())
     }
 
-    /// Tries to pre-allocate space for an object in this `Bump`, initializes
-    /// it using the closure, then returns an exclusive reference to it.
+    /// Try to allocate an object in this `Bump`, aligned to at least `align`
+    /// bytes, and return an exclusive reference to it.
     ///
-    /// See [The `_with` Method Suffix](#the-_with-method-suffix) for a
-    /// discussion on the differences between the `_with` suffixed methods and
-    /// those methods without it, their performance characteristics, and when
-    /// you might or might not choose a `_with` suffixed method.
+    /// The object is aligned to the larger of `align` and `T`'s own
+    /// alignment.
     ///
     /// ## Errors
     ///
-    /// Errors if reserving space for `T` fails.
+    /// Errors if `align` is not a power of two, or if reserving space for `T`
+    /// fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let x = bump.try_alloc_with_align(42u32, 32).unwrap();
+    /// assert_eq!(x as *mut _ as usize % 32, 0);
+    /// assert!(bump.try_alloc_with_align(42u32, 3).is_err());
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_alloc_with_align<T>(&self, val: T, align: usize) -> Result<&mut T, alloc::AllocErr> {
+        if !align.is_power_of_two() {
+            return Err(alloc::AllocErr);
+        }
+        let layout =
+            Layout::from_size_align(mem::size_of::<T>(), cmp::max(mem::align_of::<T>(), align))
+                .map_err(|_| alloc::AllocErr)?;
+        let p = self.try_alloc_layout(layout)?.as_ptr() as *mut T;
+        unsafe {
+            ptr::write(p, val);
+            Ok(&mut *p)
+        }
+    }
+
+    /// Allocates a slice of `len` default values in this `Bump`, aligned to at
+    /// least `A` bytes, and returns it wrapped in an
+    /// [`Aligned`](./struct.Aligned.html) that records the alignment in its
+    /// type.
+    ///
+    /// The slice is aligned to the larger of `A` and `T`'s own alignment.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `A` is not a power of two, or if reserving space for the
+    /// slice fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bumpalo::{Aligned, Bump};
+    ///
+    /// fn sum(xs: &Aligned<&mut [f32], 32>) -> f32 {
+    ///     // Safe to hand to aligned SIMD loads.
+    ///     assert_eq!(xs.as_ptr() as usize % 32, 0);
+    ///     xs.iter().sum()
+    /// }
+    ///
+    /// let bump = Bump::new();
+    /// let mut xs = bump.alloc_aligned_slice::<f32, 32>(8);
+    /// xs.as_mut_slice()[3] = 1.5;
+    /// assert_eq!(sum(&xs), 1.5);
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_aligned_slice<T: Default, const A: usize>(
+        &self,
+        len: usize,
+    ) -> Aligned<&mut [T], A> {
+        assert!(A.is_power_of_two(), "alignment must be a power of two");
+        self.try_alloc_aligned_slice(len).unwrap_or_else(|_| oom())
+    }
+
+    /// Try to allocate a slice of `len` default values in this `Bump`, aligned
+    /// to at least `A` bytes, and return it wrapped in an
+    /// [`Aligned`](./struct.Aligned.html).
+    ///
+    /// The slice is aligned to the larger of `A` and `T`'s own alignment.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if `A` is not a power of two, if the slice's size overflows, or
+    /// if reserving space for the slice fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let xs = bump.try_alloc_aligned_slice::<u8, 64>(3).unwrap();
+    /// assert_eq!(xs.as_ptr() as usize % 64, 0);
+    /// assert!(bump.try_alloc_aligned_slice::<u8, 3>(3).is_err());
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_alloc_aligned_slice<T: Default, const A: usize>(
+        &self,
+        len: usize,
+    ) -> Result<Aligned<&mut [T], A>, alloc::AllocErr> {
+        if !A.is_power_of_two() {
+            return Err(alloc::AllocErr);
+        }
+        let layout = Layout::array::<T>(len)
+            .and_then(|layout| layout.align_to(A))
+            .map_err(|_| alloc::AllocErr)?;
+        let dst = self.try_alloc_layout(layout)?.cast::<T>();
+        unsafe {
+            let slice = fill_slice_with(dst, len, |_| T::default());
+            Ok(Aligned::new_unchecked(slice))
+        }
+    }
+
+    /// Pre-allocate space for an object in this `Bump`, initializes it using
+    /// the closure, then returns an exclusive reference to it.
+    ///
+    /// See [The `_with` Method Suffix](#the-_with-method-suffix) for a
+    /// discussion on the differences between the `_with` suffixed methods and
+    /// those methods without it, their performance characteristics, and when
+    /// you might or might not choose a `_with` suffixed method.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for `T` fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let x = bump.alloc_with(|| "hello");
+    /// assert_eq!(*x, "hello");
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_with<F, T>(&self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        #[inline(always)]
+        unsafe fn inner_writer<T, F>(ptr: *mut T, f: F)
+        where
+            F: FnOnce() -> T,
+        {
+            // This function is translated as:
+            // - allocate space for a T on the stack
+            // - call f() with the return value being put onto this stack space
+            // - memcpy from the stack to the heap
+            //
+            // Ideally we want LLVM to always realize that doing a stack
+            // allocation is unnecessary and optimize the code so it writes
+            // directly into the heap instead. It seems we get it to realize
+            // this most consistently if we put this critical line into it's
+            // own function instead of inlining it into the surrounding code.
+            ptr::write(ptr, f())
+        }
+
+        let layout = Layout::new::<T>();
+
+        unsafe {
+            let p = self.alloc_layout(layout);
+            let p = p.as_ptr() as *mut T;
+            inner_writer(p, f);
+            &mut *p
+        }
+    }
+
+    /// Tries to pre-allocate space for an object in this `Bump`, initializes
+    /// it using the closure, then returns an exclusive reference to it.
+    ///
+    /// See [The `_with` Method Suffix](#the-_with-method-suffix) for a
+    /// discussion on the differences between the `_with` suffixed methods and
+    /// those methods without it, their performance characteristics, and when
+    /// you might or might not choose a `_with` suffixed method.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if reserving space for `T` fails.
     ///
     /// ## Example
     ///
@@ -919,7 +1599,9 @@ impl Bump {
     /// Iff the allocation fails, the closure is not run.
     ///
     /// Iff [`Err`], an allocator rewind is *attempted* and the `E` instance is
-    /// moved out of the allocator to be consumed or dropped as normal.
+    /// moved out of the allocator to be consumed or dropped as normal. If `f`
+    /// did not allocate in this `Bump` itself, the rewind always succeeds and
+    /// leaves `allocated_bytes()` as it was before the call.
     ///
     /// See [The `_with` Method Suffix](#the-_with-method-suffix) for a
     /// discussion on the differences between the `_with` suffixed methods and
@@ -999,10 +1681,14 @@ impl Bump {
                         // only allocation in this chunk.
                         //
                         // Because this is the only allocation in this chunk,
-                        // we can reset the chunk's bump finger to the start of
-                        // the chunk.
-                        current_ptr.set(current_footer_p.as_ref().data);
+                        // we can reset the chunk's bump finger to where an
+                        // empty chunk's starts out: right below its footer,
+                        // since we bump downwards.
+                        current_ptr.set(current_footer_p.cast());
                     }
+                    // The result's allocation is undone either way, so it
+                    // shouldn't be counted as served.
+                    self.allocated_objects.set(self.allocated_objects.get() - 1);
                 }
                 //SAFETY:
                 // As we received `E` semantically by value from `f`, we can
@@ -1025,7 +1711,9 @@ impl Bump {
     ///
     /// Iff the closure returns [`Err`], an allocator rewind is *attempted* and
     /// the `E` instance is moved out of the allocator to be consumed or dropped
-    /// as normal.
+    /// as normal. If `f` did not allocate in this `Bump` itself, the rewind
+    /// always succeeds and leaves `allocated_bytes()` as it was before the
+    /// call.
     ///
     /// See [The `_with` Method Suffix](#the-_with-method-suffix) for a
     /// discussion on the differences between the `_with` suffixed methods and
@@ -1105,10 +1793,14 @@ impl Bump {
                         // only allocation in this chunk.
                         //
                         // Because this is the only allocation in this chunk,
-                        // we can reset the chunk's bump finger to the start of
-                        // the chunk.
-                        current_ptr.set(current_footer_p.as_ref().data);
+                        // we can reset the chunk's bump finger to where an
+                        // empty chunk's starts out: right below its footer,
+                        // since we bump downwards.
+                        current_ptr.set(current_footer_p.cast());
                     }
+                    // The result's allocation is undone either way, so it
+                    // shouldn't be counted as served.
+                    self.allocated_objects.set(self.allocated_objects.get() - 1);
                 }
                 //SAFETY:
                 // As we received `E` semantically by value from `f`, we can
@@ -1137,214 +1829,879 @@ impl Bump {
     /// let x = bump.alloc_slice_copy(&[1, 2, 3]);
     /// assert_eq!(x, &[1, 2, 3]);
     /// ```
+    ///
+    /// References are `Copy` too, so a slice of borrowed data can be copied
+    /// into the arena. The elements keep borrowing from wherever they did
+    /// before, so the copy can't outlive that data even though the arena can:
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let text = String::from("hello arena world");
+    ///
+    /// let words: Vec<&str> = text.split(' ').collect();
+    /// let words: &mut [&str] = bump.alloc_slice_copy(&words);
+    /// words.reverse();
+    /// assert_eq!(words, ["world", "arena", "hello"]);
+    /// ```
     #[inline(always)]
     #[allow(clippy::mut_from_ref)]
     pub fn alloc_slice_copy<T>(&self, src: &[T]) -> &mut [T]
     where
         T: Copy,
     {
-        let layout = Layout::for_value(src);
-        let dst = self.alloc_layout(layout).cast::<T>();
-
-        unsafe {
-            ptr::copy_nonoverlapping(src.as_ptr(), dst.as_ptr(), src.len());
-            slice::from_raw_parts_mut(dst.as_ptr(), src.len())
-        }
+        self.try_alloc_slice_copy(src).unwrap_or_else(|_| oom())
     }
 
-    /// `Clone` a slice into this `Bump` and return an exclusive reference to
-    /// the clone. Prefer `alloc_slice_copy` if `T` is `Copy`.
+    /// Try to `Copy` a slice into this `Bump` and return an exclusive
+    /// reference to the copy.
     ///
-    /// ## Panics
+    /// ## Errors
     ///
-    /// Panics if reserving space for the slice fails.
+    /// Errors if reserving space for the slice fails.
     ///
     /// ## Example
     ///
     /// ```
-    /// #[derive(Clone, Debug, Eq, PartialEq)]
-    /// struct Sheep {
-    ///     name: String,
-    /// }
-    ///
-    /// let originals = vec![
-    ///     Sheep { name: "Alice".into() },
-    ///     Sheep { name: "Bob".into() },
-    ///     Sheep { name: "Cathy".into() },
-    /// ];
-    ///
     /// let bump = bumpalo::Bump::new();
-    /// let clones = bump.alloc_slice_clone(&originals);
-    /// assert_eq!(originals, clones);
+    /// let x = bump.try_alloc_slice_copy(&[1, 2, 3]);
+    /// assert_eq!(x, Ok(&mut [1, 2, 3][..]));
     /// ```
     #[inline(always)]
     #[allow(clippy::mut_from_ref)]
-    pub fn alloc_slice_clone<T>(&self, src: &[T]) -> &mut [T]
+    pub fn try_alloc_slice_copy<T>(&self, src: &[T]) -> Result<&mut [T], SliceAllocError>
     where
-        T: Clone,
+        T: Copy,
     {
-        let layout = Layout::for_value(src);
-        let dst = self.alloc_layout(layout).cast::<T>();
-
-        unsafe {
-            for (i, val) in src.iter().cloned().enumerate() {
-                ptr::write(dst.as_ptr().add(i), val);
-            }
-
-            slice::from_raw_parts_mut(dst.as_ptr(), src.len())
-        }
+        let dst = self.try_alloc_slice_copy_raw(src)?;
+        unsafe { Ok(slice::from_raw_parts_mut(dst.as_ptr(), src.len())) }
     }
 
-    /// `Copy` a string slice into this `Bump` and return an exclusive reference to it.
+    /// `Copy` a slice into this `Bump` and return a pointer to the first
+    /// element of the copy, which has `src.len()` elements.
+    ///
+    /// This is the same as `alloc_slice_copy`, except that no reference to
+    /// the copy is ever created, which makes it a better fit for unsafe code
+    /// that only needs the raw pointer, such as FFI glue. The copy stays valid
+    /// until this `Bump` is reset or dropped.
     ///
     /// ## Panics
     ///
-    /// Panics if reserving space for the string fails.
+    /// Panics if reserving space for the slice fails.
     ///
     /// ## Example
     ///
     /// ```
     /// let bump = bumpalo::Bump::new();
-    /// let hello = bump.alloc_str("hello world");
-    /// assert_eq!("hello world", hello);
+    /// let p = bump.alloc_slice_copy_raw(&[1, 2, 3]);
+    /// unsafe {
+    ///     *p.as_ptr().add(2) = 4;
+    ///     assert_eq!(std::slice::from_raw_parts(p.as_ptr(), 3), [1, 2, 4]);
+    /// }
     /// ```
     #[inline(always)]
-    #[allow(clippy::mut_from_ref)]
-    pub fn alloc_str(&self, src: &str) -> &mut str {
-        let buffer = self.alloc_slice_copy(src.as_bytes());
-        unsafe {
-            // This is OK, because it already came in as str, so it is guaranteed to be utf8
-            str::from_utf8_unchecked_mut(buffer)
-        }
+    pub fn alloc_slice_copy_raw<T>(&self, src: &[T]) -> NonNull<T>
+    where
+        T: Copy,
+    {
+        self.try_alloc_slice_copy_raw(src).unwrap_or_else(|_| oom())
     }
 
-    /// Allocates a new slice of size `len` into this `Bump` and returns an
-    /// exclusive reference to the copy.
-    ///
-    /// The elements of the slice are initialized using the supplied closure.
-    /// The closure argument is the position in the slice.
+    /// Try to `Copy` a slice into this `Bump` and return a pointer to the
+    /// first element of the copy, without creating a reference to it.
     ///
-    /// ## Panics
+    /// ## Errors
     ///
-    /// Panics if reserving space for the slice fails.
+    /// Errors if reserving space for the slice fails.
     ///
     /// ## Example
     ///
     /// ```
     /// let bump = bumpalo::Bump::new();
-    /// let x = bump.alloc_slice_fill_with(5, |i| 5*(i+1));
-    /// assert_eq!(x, &[5, 10, 15, 20, 25]);
+    /// let p = bump.try_alloc_slice_copy_raw(b"hello").unwrap();
+    /// assert_eq!(unsafe { *p.as_ptr() }, b'h');
     /// ```
     #[inline(always)]
-    #[allow(clippy::mut_from_ref)]
-    pub fn alloc_slice_fill_with<T, F>(&self, len: usize, mut f: F) -> &mut [T]
+    pub fn try_alloc_slice_copy_raw<T>(&self, src: &[T]) -> Result<NonNull<T>, SliceAllocError>
     where
-        F: FnMut(usize) -> T,
+        T: Copy,
     {
-        let layout = Layout::array::<T>(len).unwrap_or_else(|_| oom());
-        let dst = self.alloc_layout(layout).cast::<T>();
+        let layout = self.large_slice_layout(Layout::for_value(src));
+        let dst = self.try_alloc_layout(layout)?.cast::<T>();
 
         unsafe {
-            for i in 0..len {
-                ptr::write(dst.as_ptr().add(i), f(i));
-            }
-
-            let result = slice::from_raw_parts_mut(dst.as_ptr(), len);
-            debug_assert_eq!(Layout::for_value(result), layout);
-            result
+            ptr::copy_nonoverlapping(src.as_ptr(), dst.as_ptr(), src.len());
         }
+        Ok(dst)
     }
 
-    /// Allocates a new slice of size `len` into this `Bump` and returns an
-    /// exclusive reference to the copy.
+    /// Copy a slice into this `Bump` and return a shared reference to the
+    /// copy, unless the slice is already this `Bump`'s most recent
+    /// allocation, in which case it is returned as-is.
     ///
-    /// All elements of the slice are initialized to `value`.
+    /// The slice is reused without copying when it starts exactly at the most
+    /// recent allocation in this arena and ends within the same chunk. This
+    /// covers passing along the result of the most recent allocation
+    /// unchanged. The arena does not record the sizes of its allocations, so
+    /// a prefix of the most recent allocation is reused too. Any other slice,
+    /// including one in an older allocation or outside this arena, is copied
+    /// as with [`alloc_slice_copy`](#method.alloc_slice_copy).
+    ///
+    /// ## Safety
+    ///
+    /// The result lives as long as the borrow of this `Bump`, not as long as
+    /// `src`, and it may be `src` itself. If `src` was reused, the memory
+    /// behind it must not be mutated for as long as the result is in use,
+    /// for example through the `&mut [T]` that `alloc_slice_copy` returned.
     ///
     /// ## Panics
     ///
-    /// Panics if reserving space for the slice fails.
+    /// Panics if the slice has to be copied and reserving space for the copy
+    /// fails.
     ///
     /// ## Example
     ///
     /// ```
     /// let bump = bumpalo::Bump::new();
-    /// let x = bump.alloc_slice_fill_copy(5, 42);
-    /// assert_eq!(x, &[42, 42, 42, 42, 42]);
+    ///
+    /// let x: &[u32] = bump.alloc_slice_copy(&[1, 2, 3]);
+    /// let y = unsafe { bump.alloc_slice_copy_or_reuse(x) };
+    /// assert_eq!(x.as_ptr(), y.as_ptr());
+    ///
+    /// let z = unsafe { bump.alloc_slice_copy_or_reuse(&[1, 2, 3]) };
+    /// assert_ne!(x.as_ptr(), z.as_ptr());
+    /// assert_eq!(y, z);
     /// ```
     #[inline(always)]
-    #[allow(clippy::mut_from_ref)]
-    pub fn alloc_slice_fill_copy<T: Copy>(&self, len: usize, value: T) -> &mut [T] {
-        self.alloc_slice_fill_with(len, |_| value)
+    pub unsafe fn alloc_slice_copy_or_reuse<T>(&self, src: &[T]) -> &[T]
+    where
+        T: Copy,
+    {
+        self.try_alloc_slice_copy_or_reuse(src)
+            .unwrap_or_else(|_| oom())
     }
 
-    /// Allocates a new slice of size `len` slice into this `Bump` and return an
-    /// exclusive reference to the copy.
+    /// Like `alloc_slice_copy_or_reuse`, but returns an `Err` instead of
+    /// panicking if the slice has to be copied and reserving space for the
+    /// copy fails.
     ///
-    /// All elements of the slice are initialized to `value.clone()`.
-    ///
-    /// ## Panics
+    /// ## Safety
     ///
-    /// Panics if reserving space for the slice fails.
+    /// The same as for
+    /// [`alloc_slice_copy_or_reuse`](#method.alloc_slice_copy_or_reuse).
     ///
-    /// ## Example
+    /// ## Errors
     ///
-    /// ```
-    /// let bump = bumpalo::Bump::new();
-    /// let s: String = "Hello Bump!".to_string();
-    /// let x: &[String] = bump.alloc_slice_fill_clone(2, &s);
-    /// assert_eq!(x.len(), 2);
-    /// assert_eq!(&x[0], &s);
-    /// assert_eq!(&x[1], &s);
-    /// ```
+    /// Errors if the slice has to be copied and reserving space for the copy
+    /// fails.
     #[inline(always)]
-    #[allow(clippy::mut_from_ref)]
-    pub fn alloc_slice_fill_clone<T: Clone>(&self, len: usize, value: &T) -> &mut [T] {
-        self.alloc_slice_fill_with(len, |_| value.clone())
+    pub unsafe fn try_alloc_slice_copy_or_reuse<T>(
+        &self,
+        src: &[T],
+    ) -> Result<&[T], SliceAllocError>
+    where
+        T: Copy,
+    {
+        if src.is_empty() {
+            return Ok(&[]);
+        }
+        let footer = self.current_chunk_footer.get();
+        let footer = footer.as_ref();
+        let start = src.as_ptr() as usize;
+        let end = start + mem::size_of_val(src);
+        if start == footer.ptr.get().as_ptr() as usize && end <= footer as *const _ as usize {
+            return Ok(slice::from_raw_parts(src.as_ptr(), src.len()));
+        }
+        let dst = self.try_alloc_slice_copy_raw(src)?;
+        Ok(slice::from_raw_parts(dst.as_ptr(), src.len()))
     }
 
-    /// Allocates a new slice of size `len` slice into this `Bump` and return an
-    /// exclusive reference to the copy.
+    /// Copy a slice into this `Bump` as a series of contiguous segments, and
+    /// return a read-only [`SegmentedSlice`](./struct.SegmentedSlice.html)
+    /// view of them.
     ///
-    /// The elements are initialized using the supplied iterator.
+    /// Unlike `alloc_slice_copy`, this does not need a single block of memory
+    /// big enough for the whole slice. The copy starts out in the free space
+    /// left in the current chunk, and continues in new chunks that grow the
+    /// same way they do for other allocations, so a large slice never forces
+    /// an equally large chunk, and little of the arena's free space goes to
+    /// waste.
     ///
     /// ## Panics
     ///
-    /// Panics if reserving space for the slice fails, or if the supplied
-    /// iterator returns fewer elements than it promised.
+    /// Panics if reserving space for the segments fails.
     ///
     /// ## Example
     ///
     /// ```
     /// let bump = bumpalo::Bump::new();
-    /// let x: &[i32] = bump.alloc_slice_fill_iter([2, 3, 5].iter().cloned().map(|i| i * i));
-    /// assert_eq!(x, [4, 9, 25]);
+    /// bump.alloc_slice_fill_copy(100, 0u8);
+    ///
+    /// let src: Vec<u32> = (0..100_000).collect();
+    /// let copy = bump.alloc_slice_copy_chunked(&src);
+    ///
+    /// assert_eq!(copy.len(), src.len());
+    /// assert_eq!(copy[12_345], 12_345);
+    /// assert!(copy.iter().eq(src.iter()));
+    /// assert!(copy.segments().len() > 1);
     /// ```
-    #[inline(always)]
-    #[allow(clippy::mut_from_ref)]
-    pub fn alloc_slice_fill_iter<T, I>(&self, iter: I) -> &mut [T]
+    pub fn alloc_slice_copy_chunked<T>(&self, src: &[T]) -> SegmentedSlice<'_, T>
     where
-        I: IntoIterator<Item = T>,
-        I::IntoIter: ExactSizeIterator,
+        T: Copy,
     {
-        let mut iter = iter.into_iter();
-        self.alloc_slice_fill_with(iter.len(), |_| {
-            iter.next().expect("Iterator supplied too few elements")
-        })
+        self.try_alloc_slice_copy_chunked(src)
+            .unwrap_or_else(|_| oom())
     }
 
-    /// Allocates a new slice of size `len` slice into this `Bump` and return an
-    /// exclusive reference to the copy.
-    ///
-    /// All elements of the slice are initialized to `T::default()`.
+    /// Like `alloc_slice_copy_chunked`, but returns an `Err` instead of
+    /// panicking if reserving space for the segments fails.
     ///
-    /// ## Panics
-    ///
-    /// Panics if reserving space for the slice fails.
-    ///
-    /// ## Example
+    /// ## Errors
     ///
-    /// ```
-    /// let bump = bumpalo::Bump::new();
+    /// Errors if reserving space for the segments fails.
+    pub fn try_alloc_slice_copy_chunked<T>(
+        &self,
+        src: &[T],
+    ) -> Result<SegmentedSlice<'_, T>, SliceAllocError>
+    where
+        T: Copy,
+    {
+        let size = mem::size_of::<T>();
+        if size == 0 || src.is_empty() {
+            let segment: &[T] = self.try_alloc_slice_copy(src)?;
+            let segments = if src.is_empty() {
+                &[][..]
+            } else {
+                slice::from_ref(&segment)
+            };
+            let segments = self.try_alloc_slice_copy(segments)?;
+            return Ok(SegmentedSlice::new(segments, src.len()));
+        }
+
+        // Each new chunk is at least twice as big as the last, so this many
+        // segments covers any slice that fits in memory. Only if the global
+        // allocator keeps refusing bigger chunks can it run out, and then the
+        // rest of the slice goes into one last, contiguous segment.
+        const MAX_SEGMENTS: usize = mem::size_of::<usize>() * 8;
+        let mut segments: [&[T]; MAX_SEGMENTS] = [&[]; MAX_SEGMENTS];
+        let mut num_segments = 0;
+        let mut rest = src;
+        while !rest.is_empty() {
+            if num_segments == MAX_SEGMENTS - 1 {
+                segments[num_segments] = self.try_alloc_slice_copy(rest)?;
+                num_segments += 1;
+                break;
+            }
+
+            let fits = unsafe {
+                let footer = self.current_chunk_footer.get();
+                let footer = footer.as_ref();
+                let top = footer.ptr.get().as_ptr() as usize & !(mem::align_of::<T>() - 1);
+                let data = footer.data.as_ptr() as usize;
+                top.saturating_sub(data) / size
+            };
+
+            if fits == 0 {
+                // Get a new chunk, sized as it would be for any other
+                // allocation. The next segment then fills the whole chunk,
+                // whatever size it ended up with.
+                self.grow_to_new_chunk(Layout::new::<T>())
+                    .ok_or(SliceAllocError::OutOfMemory)?;
+                continue;
+            }
+
+            let n = cmp::min(rest.len(), fits);
+            let layout = slice_layout::<T>(n)?;
+            let dst = self.try_alloc_layout(layout)?.cast::<T>();
+            segments[num_segments] = unsafe {
+                ptr::copy_nonoverlapping(rest.as_ptr(), dst.as_ptr(), n);
+                slice::from_raw_parts(dst.as_ptr() as *const T, n)
+            };
+            num_segments += 1;
+            rest = &rest[n..];
+        }
+
+        let segments = self.try_alloc_slice_copy(&segments[..num_segments])?;
+        Ok(SegmentedSlice::new(segments, src.len()))
+    }
+
+    /// Copy a slice of possibly uninitialized values into this `Bump` and
+    /// return an exclusive reference to the copy.
+    ///
+    /// The values are copied bytewise, so `src` does not have to be
+    /// initialized, and the copy is exactly as initialized as `src` was. This
+    /// is the same as `alloc_slice_copy` for `MaybeUninit<T>`, spelled out for
+    /// discoverability.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for the slice fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let bump = bumpalo::Bump::new();
+    /// let src = [MaybeUninit::new(1u8), MaybeUninit::uninit(), MaybeUninit::new(3)];
+    /// let copy = bump.alloc_slice_copy_uninit(&src);
+    /// assert_eq!(copy.len(), 3);
+    /// assert_eq!(unsafe { copy[2].assume_init() }, 3);
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_copy_uninit<T>(
+        &self,
+        src: &[mem::MaybeUninit<T>],
+    ) -> &mut [mem::MaybeUninit<T>]
+    where
+        T: Copy,
+    {
+        self.alloc_slice_copy(src)
+    }
+
+    /// Attempt to copy a slice of possibly uninitialized values into this
+    /// `Bump` and return an exclusive reference to the copy.
+    ///
+    /// See [`alloc_slice_copy_uninit`](#method.alloc_slice_copy_uninit).
+    ///
+    /// ## Errors
+    ///
+    /// Errors if reserving space for the slice fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let bump = bumpalo::Bump::new();
+    /// let src = [MaybeUninit::<u32>::uninit(); 4];
+    /// let copy = bump.try_alloc_slice_copy_uninit(&src).unwrap();
+    /// assert_eq!(copy.len(), 4);
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_alloc_slice_copy_uninit<T>(
+        &self,
+        src: &[mem::MaybeUninit<T>],
+    ) -> Result<&mut [mem::MaybeUninit<T>], SliceAllocError>
+    where
+        T: Copy,
+    {
+        self.try_alloc_slice_copy(src)
+    }
+
+    /// `Clone` a slice into this `Bump` and return an exclusive reference to
+    /// the clone. Prefer `alloc_slice_copy` if `T` is `Copy`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for the slice fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// #[derive(Clone, Debug, Eq, PartialEq)]
+    /// struct Sheep {
+    ///     name: String,
+    /// }
+    ///
+    /// let originals = vec![
+    ///     Sheep { name: "Alice".into() },
+    ///     Sheep { name: "Bob".into() },
+    ///     Sheep { name: "Cathy".into() },
+    /// ];
+    ///
+    /// let bump = bumpalo::Bump::new();
+    /// let clones = bump.alloc_slice_clone(&originals);
+    /// assert_eq!(originals, clones);
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_clone<T>(&self, src: &[T]) -> &mut [T]
+    where
+        T: Clone,
+    {
+        self.try_alloc_slice_clone(src).unwrap_or_else(|_| oom())
+    }
+
+    /// Try to `Clone` a slice into this `Bump` and return an exclusive
+    /// reference to the clone. Prefer `try_alloc_slice_copy` if `T` is `Copy`.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if reserving space for the slice fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let originals = vec![String::from("Alice"), String::from("Bob")];
+    ///
+    /// let bump = bumpalo::Bump::new();
+    /// let clones = bump.try_alloc_slice_clone(&originals).unwrap();
+    /// assert_eq!(originals, clones);
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_alloc_slice_clone<T>(&self, src: &[T]) -> Result<&mut [T], SliceAllocError>
+    where
+        T: Clone,
+    {
+        let layout = Layout::for_value(src);
+        let dst = self.try_alloc_layout(layout)?.cast::<T>();
+
+        unsafe {
+            for (i, val) in src.iter().cloned().enumerate() {
+                ptr::write(dst.as_ptr().add(i), val);
+            }
+
+            Ok(slice::from_raw_parts_mut(dst.as_ptr(), src.len()))
+        }
+    }
+
+    /// Move an array into this `Bump` and return an exclusive reference to it
+    /// as a slice.
+    ///
+    /// The whole array is moved into the arena at once, rather than element by
+    /// element, and `T` need not be `Copy` or `Clone`. If you want to keep the
+    /// array type, use [`alloc`](#method.alloc), which returns a `&mut [T; N]`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for the array fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let x = bump.alloc_slice_move([String::from("a"), String::from("b")]);
+    /// assert_eq!(x, &["a", "b"]);
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_move<T, const N: usize>(&self, array: [T; N]) -> &mut [T] {
+        self.alloc(array)
+    }
+
+    /// `Copy` a string slice into this `Bump` and return an exclusive reference to it.
+    ///
+    /// The copy is fresh, so it can be modified in place through the methods
+    /// on `&mut str`, which keep it valid UTF-8.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for the string fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let hello = bump.alloc_str("hello world");
+    /// assert_eq!("hello world", hello);
+    ///
+    /// hello.make_ascii_uppercase();
+    /// assert_eq!("HELLO WORLD", hello);
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_str(&self, src: &str) -> &mut str {
+        self.try_alloc_str(src).unwrap_or_else(|_| oom())
+    }
+
+    /// Attempts to `Copy` a string slice into this `Bump` and return an
+    /// exclusive reference to it.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if reserving space for the string fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let hello = bump.try_alloc_str("hello world").unwrap();
+    /// assert_eq!("hello world", hello);
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_alloc_str(&self, src: &str) -> Result<&mut str, alloc::AllocErr> {
+        let buffer = self.try_alloc_slice_copy(src.as_bytes())?;
+        unsafe {
+            // This is OK, because it already came in as str, so it is guaranteed to be utf8
+            Ok(str::from_utf8_unchecked_mut(buffer))
+        }
+    }
+
+    /// Copies each string into this `Bump` as a NUL-terminated C string, and
+    /// returns a pointer to a null-terminated array of pointers to them, the
+    /// shape that `argv` and `execvp` expect.
+    ///
+    /// The strings and the array all live as long as this `Bump` is not reset
+    /// or dropped.
+    ///
+    /// This method is only available with the `std` cargo feature enabled.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any string contains a NUL byte, or if reserving space for the
+    /// strings or the array fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")]
+    /// # {
+    /// use std::ffi::CStr;
+    ///
+    /// let bump = bumpalo::Bump::new();
+    /// let argv = bump.alloc_cstr_array(vec!["ls", "-l", "/tmp"]);
+    ///
+    /// unsafe {
+    ///     assert_eq!(CStr::from_ptr(*argv).to_str(), Ok("ls"));
+    ///     assert_eq!(CStr::from_ptr(*argv.add(2)).to_str(), Ok("/tmp"));
+    ///     assert!((*argv.add(3)).is_null());
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn alloc_cstr_array<'a, I>(&self, strs: I) -> *const *const c_char
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        self.try_alloc_cstr_array(strs).unwrap_or_else(|_| oom())
+    }
+
+    /// Like `alloc_cstr_array`, but returns an `Err` instead of panicking if
+    /// reserving space for the strings or the array fails.
+    ///
+    /// This method is only available with the `std` cargo feature enabled.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if reserving space for the strings or the array fails.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any string contains a NUL byte.
+    #[cfg(feature = "std")]
+    pub fn try_alloc_cstr_array<'a, I>(
+        &self,
+        strs: I,
+    ) -> Result<*const *const c_char, alloc::AllocErr>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        // Gather the pointers on the global heap, which this method already
+        // needs `std` for, so that `strs` only has to be walked once and can
+        // be any iterator.
+        let mut ptrs = std::vec::Vec::new();
+        for s in strs {
+            assert!(
+                !s.as_bytes().contains(&0),
+                "C strings must not contain NUL bytes"
+            );
+            let layout = Layout::array::<u8>(s.len() + 1).map_err(|_| alloc::AllocErr)?;
+            let dst = self.try_alloc_layout(layout)?.as_ptr();
+            unsafe {
+                ptr::copy_nonoverlapping(s.as_ptr(), dst, s.len());
+                ptr::write(dst.add(s.len()), 0);
+            }
+            ptrs.push(dst as *const c_char);
+        }
+        ptrs.push(ptr::null());
+        Ok(self.try_alloc_slice_copy_raw(&ptrs)?.as_ptr() as *const *const c_char)
+    }
+
+    /// Allocates a new slice of size `len` into this `Bump` and returns an
+    /// exclusive reference to the copy.
+    ///
+    /// The elements of the slice are initialized using the supplied closure.
+    /// The closure argument is the position in the slice. The closure is called
+    /// exactly once for each position, in increasing order, so it may carry
+    /// state from one element to the next. If it panics, the elements
+    /// initialized so far are dropped before the panic continues.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for the slice fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let x = bump.alloc_slice_fill_with(5, |i| 5*(i+1));
+    /// assert_eq!(x, &[5, 10, 15, 20, 25]);
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_fill_with<T, F>(&self, len: usize, f: F) -> &mut [T]
+    where
+        F: FnMut(usize) -> T,
+    {
+        self.try_alloc_slice_fill_with(len, f)
+            .unwrap_or_else(|_| oom())
+    }
+
+    /// Tries to allocate a new slice of size `len` into this `Bump` and
+    /// returns an exclusive reference to the copy.
+    ///
+    /// The elements of the slice are initialized using the supplied closure,
+    /// with the same guarantees as for
+    /// [`alloc_slice_fill_with`](#method.alloc_slice_fill_with). The closure is
+    /// not called if the allocation fails.
+    ///
+    /// ## Errors
+    ///
+    /// Errors with [`LayoutOverflow`](`SliceAllocError::LayoutOverflow`) if
+    /// the slice's size overflows `isize`, or with
+    /// [`OutOfMemory`](`SliceAllocError::OutOfMemory`) if reserving space for
+    /// the slice fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let x = bump.try_alloc_slice_fill_with(5, |i| 5*(i+1));
+    /// assert_eq!(x, Ok(&mut [5, 10, 15, 20, 25][..]));
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_alloc_slice_fill_with<T, F>(
+        &self,
+        len: usize,
+        f: F,
+    ) -> Result<&mut [T], SliceAllocError>
+    where
+        F: FnMut(usize) -> T,
+    {
+        let layout = slice_layout::<T>(len)?;
+        let dst = self.try_alloc_layout(layout)?.cast::<T>();
+
+        unsafe {
+            let result = fill_slice_with(dst, len, f);
+            debug_assert_eq!(Layout::for_value(result), layout);
+            Ok(result)
+        }
+    }
+
+    /// Allocates a new slice of size `len` into this `Bump` and returns an
+    /// exclusive reference to the copy.
+    ///
+    /// All elements of the slice are initialized to `value`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for the slice fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let x = bump.alloc_slice_fill_copy(5, 42);
+    /// assert_eq!(x, &[42, 42, 42, 42, 42]);
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_fill_copy<T: Copy>(&self, len: usize, value: T) -> &mut [T] {
+        self.try_alloc_slice_fill_copy(len, value)
+            .unwrap_or_else(|_| oom())
+    }
+
+    /// Tries to allocate a new slice of size `len` into this `Bump` and
+    /// returns an exclusive reference to the copy.
+    ///
+    /// All elements of the slice are initialized to `value`.
+    ///
+    /// ## Errors
+    ///
+    /// Errors with [`LayoutOverflow`](`SliceAllocError::LayoutOverflow`) if
+    /// the slice's size overflows `isize`, or with
+    /// [`OutOfMemory`](`SliceAllocError::OutOfMemory`) if reserving space for
+    /// the slice fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let x = bump.try_alloc_slice_fill_copy(5, 42);
+    /// assert_eq!(x, Ok(&mut [42, 42, 42, 42, 42][..]));
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_alloc_slice_fill_copy<T: Copy>(
+        &self,
+        len: usize,
+        value: T,
+    ) -> Result<&mut [T], SliceAllocError> {
+        let layout = slice_layout::<T>(len)?;
+        let layout = self.large_slice_layout(layout);
+        let dst = self.try_alloc_layout(layout)?.cast::<T>();
+
+        unsafe {
+            for i in 0..len {
+                ptr::write(dst.as_ptr().add(i), value);
+            }
+            Ok(slice::from_raw_parts_mut(dst.as_ptr(), len))
+        }
+    }
+
+    /// Allocates a new slice of size `len` slice into this `Bump` and return an
+    /// exclusive reference to the copy.
+    ///
+    /// All elements of the slice are initialized to `value.clone()`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for the slice fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let s: String = "Hello Bump!".to_string();
+    /// let x: &[String] = bump.alloc_slice_fill_clone(2, &s);
+    /// assert_eq!(x.len(), 2);
+    /// assert_eq!(&x[0], &s);
+    /// assert_eq!(&x[1], &s);
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_fill_clone<T: Clone>(&self, len: usize, value: &T) -> &mut [T] {
+        self.alloc_slice_fill_with(len, |_| value.clone())
+    }
+
+    /// Tries to allocate a new slice of size `len` into this `Bump` and
+    /// returns an exclusive reference to the copy.
+    ///
+    /// All elements of the slice are initialized to `value.clone()`.
+    ///
+    /// ## Errors
+    ///
+    /// Errors with [`LayoutOverflow`](`SliceAllocError::LayoutOverflow`) if
+    /// the slice's size overflows `isize`, or with
+    /// [`OutOfMemory`](`SliceAllocError::OutOfMemory`) if reserving space for
+    /// the slice fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let s: String = "Hello Bump!".to_string();
+    /// let x: &[String] = bump.try_alloc_slice_fill_clone(2, &s).unwrap();
+    /// assert_eq!(x, [s.clone(), s]);
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_alloc_slice_fill_clone<T: Clone>(
+        &self,
+        len: usize,
+        value: &T,
+    ) -> Result<&mut [T], SliceAllocError> {
+        self.try_alloc_slice_fill_with(len, |_| value.clone())
+    }
+
+    /// Allocates a new slice of size `len` slice into this `Bump` and return an
+    /// exclusive reference to the copy.
+    ///
+    /// The elements are initialized using the supplied iterator. Space is
+    /// reserved for as many elements as the iterator reports with
+    /// `ExactSizeIterator::len`, but the iterator is not trusted to be exact:
+    ///
+    /// * If it runs out early, only the elements it did supply are returned.
+    ///   When the slice is still the most recent allocation, the unused space
+    ///   at its end is given back to the arena.
+    ///
+    /// * If it has more elements than it reported, the extra ones are never
+    ///   taken from it and are dropped along with the iterator.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for the slice fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let x: &[i32] = bump.alloc_slice_fill_iter([2, 3, 5].iter().cloned().map(|i| i * i));
+    /// assert_eq!(x, [4, 9, 25]);
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_fill_iter<T, I>(&self, iter: I) -> &mut [T]
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        self.try_alloc_slice_fill_iter(iter)
+            .unwrap_or_else(|_| oom())
+    }
+
+    /// Tries to allocate a new slice of size `len` slice into this `Bump` and
+    /// return an exclusive reference to the copy.
+    ///
+    /// The elements are initialized using the supplied iterator, which, like
+    /// with [`alloc_slice_fill_iter`](#method.alloc_slice_fill_iter), may
+    /// supply fewer or more elements than it reports.
+    ///
+    /// ## Errors
+    ///
+    /// Errors with [`LayoutOverflow`](`SliceAllocError::LayoutOverflow`) if
+    /// the slice's size overflows `isize`, or with
+    /// [`OutOfMemory`](`SliceAllocError::OutOfMemory`) if reserving space for
+    /// the slice fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let x = bump.try_alloc_slice_fill_iter([2, 3, 5].iter().cloned().map(|i| i * i));
+    /// assert_eq!(x, Ok(&mut [4, 9, 25][..]));
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_alloc_slice_fill_iter<T, I>(&self, iter: I) -> Result<&mut [T], SliceAllocError>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let mut iter = iter.into_iter();
+        let len = iter.len();
+        let layout = slice_layout::<T>(len)?;
+        let mut dst = self.try_alloc_layout(layout)?.cast::<T>();
+
+        let mut n = 0;
+        while n < len {
+            match iter.next() {
+                Some(x) => unsafe {
+                    ptr::write(dst.as_ptr().add(n), x);
+                    n += 1;
+                },
+                None => break,
+            }
+        }
+
+        unsafe {
+            if n < len {
+                dst = self
+                    .shrink(dst.cast(), layout, n * mem::size_of::<T>())?
+                    .cast();
+            }
+            Ok(slice::from_raw_parts_mut(dst.as_ptr(), n))
+        }
+    }
+
+    /// Allocates a new slice of size `len` slice into this `Bump` and return an
+    /// exclusive reference to the copy.
+    ///
+    /// All elements of the slice are initialized to `T::default()`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for the slice fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
     /// let x = bump.alloc_slice_fill_default::<u32>(5);
     /// assert_eq!(x, &[0, 0, 0, 0, 0]);
     /// ```
@@ -1354,45 +2711,423 @@ impl Bump {
         self.alloc_slice_fill_with(len, |_| T::default())
     }
 
+    /// Tries to allocate a new slice of size `len` slice into this `Bump` and
+    /// return an exclusive reference to the copy.
+    ///
+    /// All elements of the slice are initialized to `T::default()`.
+    ///
+    /// ## Errors
+    ///
+    /// Errors with [`LayoutOverflow`](`SliceAllocError::LayoutOverflow`) if
+    /// the slice's size overflows `isize`, or with
+    /// [`OutOfMemory`](`SliceAllocError::OutOfMemory`) if reserving space for
+    /// the slice fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let x = bump.try_alloc_slice_fill_default::<u32>(5);
+    /// assert_eq!(x, Ok(&mut [0, 0, 0, 0, 0][..]));
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_alloc_slice_fill_default<T: Default>(
+        &self,
+        len: usize,
+    ) -> Result<&mut [T], SliceAllocError> {
+        self.try_alloc_slice_fill_with(len, |_| T::default())
+    }
+
+    /// Allocates `len` uninitialized bytes in this `Bump`, for example as a
+    /// scratch buffer, and returns an exclusive reference to them.
+    ///
+    /// The bytes are returned as `MaybeUninit<u8>`, because reading
+    /// uninitialized memory as `u8` is undefined behavior. Use
+    /// [`alloc_zeroed_bytes`](#method.alloc_zeroed_bytes) to get a `&mut [u8]`
+    /// that is safe to read right away.
+    ///
+    /// If `len` is zero, an empty slice is returned without touching the bump
+    /// pointer.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for the bytes fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let bump = bumpalo::Bump::new();
+    /// let buf = bump.alloc_bytes(4);
+    /// assert_eq!(buf.len(), 4);
+    ///
+    /// for (i, byte) in buf.iter_mut().enumerate() {
+    ///     *byte = MaybeUninit::new(i as u8);
+    /// }
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_bytes(&self, len: usize) -> &mut [mem::MaybeUninit<u8>] {
+        self.try_alloc_bytes(len).unwrap_or_else(|_| oom())
+    }
+
+    /// Tries to allocate `len` uninitialized bytes in this `Bump` and returns
+    /// an exclusive reference to them.
+    ///
+    /// See [`alloc_bytes`](#method.alloc_bytes) for details.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if `len` is larger than `isize::MAX`, or if reserving space for
+    /// the bytes fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// assert_eq!(bump.try_alloc_bytes(16).unwrap().len(), 16);
+    /// assert!(bump.try_alloc_bytes(usize::MAX).is_err());
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_alloc_bytes(
+        &self,
+        len: usize,
+    ) -> Result<&mut [mem::MaybeUninit<u8>], alloc::AllocErr> {
+        // Older versions of `Layout` don't reject sizes over `isize::MAX`.
+        if len > isize::MAX as usize {
+            return Err(alloc::AllocErr);
+        }
+        let layout = Layout::array::<u8>(len).map_err(|_| alloc::AllocErr)?;
+        let layout = self.large_slice_layout(layout);
+        let dst = self
+            .try_alloc_layout(layout)?
+            .cast::<mem::MaybeUninit<u8>>();
+        unsafe { Ok(slice::from_raw_parts_mut(dst.as_ptr(), len)) }
+    }
+
+    /// Allocates `len` zeroed bytes in this `Bump`, for example as a scratch
+    /// buffer, and returns an exclusive reference to them.
+    ///
+    /// This is the same as `alloc_slice_fill_copy(len, 0u8)`. If `len` is
+    /// zero, an empty slice is returned without touching the bump pointer.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for the bytes fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let buf = bump.alloc_zeroed_bytes(4);
+    /// assert_eq!(buf, [0, 0, 0, 0]);
+    ///
+    /// buf[..2].copy_from_slice(b"hi");
+    /// assert_eq!(buf, b"hi\0\0");
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_zeroed_bytes(&self, len: usize) -> &mut [u8] {
+        self.try_alloc_zeroed_bytes(len).unwrap_or_else(|_| oom())
+    }
+
+    /// Tries to allocate `len` zeroed bytes in this `Bump` and returns an
+    /// exclusive reference to them.
+    ///
+    /// See [`alloc_zeroed_bytes`](#method.alloc_zeroed_bytes) for details.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if `len` is larger than `isize::MAX`, or if reserving space for
+    /// the bytes fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// assert_eq!(bump.try_alloc_zeroed_bytes(3), Ok(&mut [0, 0, 0][..]));
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_alloc_zeroed_bytes(&self, len: usize) -> Result<&mut [u8], alloc::AllocErr> {
+        let bytes = self.try_alloc_bytes(len)?;
+        unsafe {
+            ptr::write_bytes(bytes.as_mut_ptr(), 0, len);
+            Ok(slice::from_raw_parts_mut(
+                bytes.as_mut_ptr() as *mut u8,
+                len,
+            ))
+        }
+    }
+
     /// Allocate space for an object with the given `Layout`.
     ///
-    /// The returned pointer points at uninitialized memory, and should be
-    /// initialized with
+    /// The returned pointer points at uninitialized memory, and should be
+    /// initialized with
+    /// [`std::ptr::write`](https://doc.rust-lang.org/std/ptr/fn.write.html).
+    ///
+    /// # Panics
+    ///
+    /// Panics if reserving space matching `layout` fails.
+    #[inline(always)]
+    pub fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        self.try_alloc_layout(layout).unwrap_or_else(|_| oom())
+    }
+
+    /// Attempts to allocate space for an object with the given `Layout` or else returns
+    /// an `Err`.
+    ///
+    /// The returned pointer points at uninitialized memory, and should be
+    /// initialized with
+    /// [`std::ptr::write`](https://doc.rust-lang.org/std/ptr/fn.write.html).
+    ///
+    /// # Errors
+    ///
+    /// Errors if reserving space matching `layout` fails.
+    #[inline(always)]
+    pub fn try_alloc_layout(&self, layout: Layout) -> Result<NonNull<u8>, alloc::AllocErr> {
+        // Zero-sized allocations never touch the bump pointer, so that no
+        // number of them can use up a chunk with alignment padding. When the
+        // layout is known at compile time, as it is for `alloc::<T>`, this
+        // check is resolved during monomorphization.
+        let p = if layout.size() == 0 {
+            unsafe { NonNull::new_unchecked(layout.align() as *mut u8) }
+        } else if let Some(p) = self.try_alloc_layout_fast(layout) {
+            p
+        } else {
+            self.alloc_layout_slow(layout).ok_or(alloc::AllocErr {})?
+        };
+        self.allocated_objects.set(self.allocated_objects.get() + 1);
+        Ok(p)
+    }
+
+    /// Allocate space for an object with the given `Layout`, and initialize it
+    /// in place by calling `init` with a pointer to that space.
+    ///
+    /// This is useful for types whose size is only known at runtime, such as a
+    /// header followed by a trailing array, whose fields must be written
+    /// individually.
+    ///
+    /// ## Safety
+    ///
+    /// `init` is handed a pointer to `layout.size()` bytes of uninitialized
+    /// memory, aligned to `layout.align()`, and must not write outside of them.
+    /// The memory is treated as initialized only to the extent that `init`
+    /// initializes it.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space matching `layout` fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::alloc::Layout;
+    ///
+    /// let bump = bumpalo::Bump::new();
+    ///
+    /// // A `u32` length followed by that many `u16`s.
+    /// let (layout, offset) = Layout::new::<u32>()
+    ///     .extend(Layout::array::<u16>(3).unwrap())
+    ///     .unwrap();
+    ///
+    /// let p = unsafe {
+    ///     bump.alloc_layout_with(layout, |p| {
+    ///         (p as *mut u32).write(3);
+    ///         let items = p.add(offset) as *mut u16;
+    ///         for i in 0..3 {
+    ///             items.add(i).write(i as u16 * 10);
+    ///         }
+    ///     })
+    /// };
+    ///
+    /// unsafe {
+    ///     assert_eq!(*(p.as_ptr() as *const u32), 3);
+    ///     let items = std::slice::from_raw_parts(p.as_ptr().add(offset) as *const u16, 3);
+    ///     assert_eq!(items, [0, 10, 20]);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub unsafe fn alloc_layout_with<F>(&self, layout: Layout, init: F) -> NonNull<u8>
+    where
+        F: FnOnce(*mut u8),
+    {
+        self.try_alloc_layout_with(layout, init)
+            .unwrap_or_else(|_| oom())
+    }
+
+    /// Attempts to allocate space for an object with the given `Layout` and
+    /// initialize it in place with `init`, or else returns an `Err`.
+    ///
+    /// `init` is only called if the allocation succeeds.
+    ///
+    /// ## Safety
+    ///
+    /// The same requirements as for
+    /// [`alloc_layout_with`](#method.alloc_layout_with) apply.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if reserving space matching `layout` fails.
+    #[inline(always)]
+    pub unsafe fn try_alloc_layout_with<F>(
+        &self,
+        layout: Layout,
+        init: F,
+    ) -> Result<NonNull<u8>, alloc::AllocErr>
+    where
+        F: FnOnce(*mut u8),
+    {
+        let p = self.try_alloc_layout(layout)?;
+        init(p.as_ptr());
+        Ok(p)
+    }
+
+    /// Shrinks a block previously allocated in this `Bump` with `layout` down
+    /// to `new_size` bytes, without allocating, and returns the block's new
+    /// address, which may differ from `ptr`.
+    ///
+    /// If the block is the most recent allocation in the current chunk, the
+    /// bump pointer is rewound to reclaim the excess `layout.size() -
+    /// new_size` bytes (rounded down to a multiple of `layout.align()`), so
+    /// that later allocations can reuse them. Because allocation proceeds
+    /// downwards in memory, this **moves** the block's first `new_size` bytes
+    /// up by the amount reclaimed, and the returned pointer must be used
+    /// instead of `ptr` from then on. Otherwise nothing can be reclaimed, and
+    /// `ptr` is returned as-is.
+    ///
+    /// ## Safety
+    ///
+    /// `ptr` must point to a block currently allocated in this `Bump` with
+    /// `layout`, and nothing may refer to the block's bytes past `new_size`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `new_size` is greater than `layout.size()`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::alloc::Layout;
+    ///
+    /// let bump = bumpalo::Bump::new();
+    ///
+    /// let layout = Layout::array::<u8>(100).unwrap();
+    /// let p = bump.alloc_layout(layout);
+    /// unsafe { p.as_ptr().write_bytes(7, 100) };
+    /// assert_eq!(bump.allocated_bytes(), 100);
+    ///
+    /// let p = unsafe { bump.shrink_last(p, layout, 10) };
+    /// assert_eq!(bump.allocated_bytes(), 10);
+    /// assert_eq!(unsafe { std::slice::from_raw_parts(p.as_ptr(), 10) }, [7; 10]);
+    /// ```
+    pub unsafe fn shrink_last(
+        &self,
+        ptr: NonNull<u8>,
+        layout: Layout,
+        new_size: usize,
+    ) -> NonNull<u8> {
+        assert!(
+            new_size <= layout.size(),
+            "`new_size` must not be greater than the block's size"
+        );
+        self.shrink(ptr, layout, new_size).unwrap_or(ptr)
+    }
+
+    /// Allocate space for a record whose fields have the given `Layout`s, laid
+    /// out in order as a `#[repr(C)]` struct would be.
+    ///
+    /// Returns a pointer to the start of the record, along with the offset of
+    /// each field from that pointer. The offsets are themselves allocated
+    /// within this `Bump`.
+    ///
+    /// The returned pointer points at uninitialized memory, and each field
+    /// should be initialized with
     /// [`std::ptr::write`](https://doc.rust-lang.org/std/ptr/fn.write.html).
     ///
     /// # Panics
     ///
-    /// Panics if reserving space matching `layout` fails.
-    #[inline(always)]
-    pub fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
-        self.try_alloc_layout(layout).unwrap_or_else(|_| oom())
+    /// Panics if the record's layout overflows, or if reserving space for it
+    /// fails.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::alloc::Layout;
+    ///
+    /// #[repr(C)]
+    /// struct Record {
+    ///     a: u8,
+    ///     b: u32,
+    ///     c: u16,
+    /// }
+    ///
+    /// let bump = bumpalo::Bump::new();
+    /// let (ptr, offsets) = bump.alloc_layout_repr_c(&[
+    ///     Layout::new::<u8>(),
+    ///     Layout::new::<u32>(),
+    ///     Layout::new::<u16>(),
+    /// ]);
+    /// assert_eq!(offsets, &[0, 4, 8]);
+    ///
+    /// let record = unsafe {
+    ///     ptr.as_ptr().add(offsets[0]).write(1_u8);
+    ///     (ptr.as_ptr().add(offsets[1]) as *mut u32).write(2);
+    ///     (ptr.as_ptr().add(offsets[2]) as *mut u16).write(3);
+    ///     &*(ptr.as_ptr() as *const Record)
+    /// };
+    /// assert_eq!((record.a, record.b, record.c), (1, 2, 3));
+    /// ```
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_layout_repr_c(&self, fields: &[Layout]) -> (NonNull<u8>, &mut [usize]) {
+        self.try_alloc_layout_repr_c(fields)
+            .unwrap_or_else(|_| oom())
     }
 
-    /// Attempts to allocate space for an object with the given `Layout` or else returns
-    /// an `Err`.
+    /// Attempts to allocate space for a record whose fields have the given
+    /// `Layout`s, laid out in order as a `#[repr(C)]` struct would be, or else
+    /// returns an `Err`.
     ///
-    /// The returned pointer points at uninitialized memory, and should be
-    /// initialized with
-    /// [`std::ptr::write`](https://doc.rust-lang.org/std/ptr/fn.write.html).
+    /// See [`alloc_layout_repr_c`](#method.alloc_layout_repr_c) for details.
     ///
     /// # Errors
     ///
-    /// Errors if reserving space matching `layout` fails.
-    #[inline(always)]
-    pub fn try_alloc_layout(&self, layout: Layout) -> Result<NonNull<u8>, alloc::AllocErr> {
-        if let Some(p) = self.try_alloc_layout_fast(layout) {
-            Ok(p)
-        } else {
-            self.alloc_layout_slow(layout).ok_or(alloc::AllocErr {})
+    /// Errors if the record's layout overflows, or if reserving space for it
+    /// fails.
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_alloc_layout_repr_c(
+        &self,
+        fields: &[Layout],
+    ) -> Result<(NonNull<u8>, &mut [usize]), alloc::AllocErr> {
+        let offsets_layout = Layout::array::<usize>(fields.len()).map_err(|_| alloc::AllocErr)?;
+        let offsets = self.try_alloc_layout(offsets_layout)?.cast::<usize>();
+
+        let mut layout = unsafe { layout_from_size_align(0, 1) };
+        for (i, field) in fields.iter().enumerate() {
+            let (extended, offset) = layout.extend(*field).map_err(|_| alloc::AllocErr)?;
+            layout = extended;
+            unsafe {
+                ptr::write(offsets.as_ptr().add(i), offset);
+            }
         }
+        // Trailing padding, so that the record's size is a multiple of its
+        // alignment, just like a `#[repr(C)]` struct.
+        let layout = layout.pad_to_align();
+
+        let ptr = self.try_alloc_layout(layout)?;
+        let offsets = unsafe { slice::from_raw_parts_mut(offsets.as_ptr(), fields.len()) };
+        Ok((ptr, offsets))
     }
 
     #[inline(always)]
     fn try_alloc_layout_fast(&self, layout: Layout) -> Option<NonNull<u8>> {
-        // We don't need to check for ZSTs here since they will automatically
-        // be handled properly: the pointer will be bumped by zero bytes,
-        // modulo alignment. This keeps the fast path optimized for non-ZSTs,
-        // which are much more common.
+        // Zero-sized allocations are answered by `try_alloc_layout` without
+        // getting here, but a zero-sized layout is still handled correctly:
+        // the pointer is bumped by zero bytes, modulo alignment.
         unsafe {
             let footer = self.current_chunk_footer.get();
             let footer = footer.as_ref();
@@ -1401,6 +3136,23 @@ impl Bump {
             debug_assert!(start <= ptr);
             debug_assert!(ptr <= footer as *const _ as usize);
 
+            // For small objects, whose size and alignment are usually known at
+            // compile time, a single comparison against the room left in the
+            // chunk proves that neither bumping down by the size nor rounding
+            // down to the alignment can go past the chunk's start, so there is
+            // no need for the overflow check below. This is conservative by up
+            // to `align - 1` bytes, so when it fails we fall back to the exact
+            // check rather than to a new chunk.
+            if layout.size() <= SMALL_OBJECT_MAX
+                && layout.align() <= SMALL_OBJECT_MAX
+                && ptr - start >= layout.size() + (layout.align() - 1)
+            {
+                let aligned_ptr = (ptr - layout.size()) & !(layout.align() - 1);
+                let aligned_ptr = NonNull::new_unchecked(aligned_ptr as *mut u8);
+                footer.ptr.set(aligned_ptr);
+                return Some(aligned_ptr);
+            }
+
             let ptr = ptr.checked_sub(layout.size())?;
             let aligned_ptr = ptr & !(layout.align() - 1);
 
@@ -1440,6 +3192,34 @@ impl Bump {
         unsafe {
             let size = layout.size();
 
+            let new_footer = self.grow_to_new_chunk(layout)?;
+            let new_footer = new_footer.as_ref();
+
+            // Move the bump ptr finger down to allocate room for `val`. We know
+            // this can't overflow because we successfully allocated a chunk of
+            // at least the requested size.
+            let ptr = new_footer.ptr.get().as_ptr() as usize - size;
+            // Round the pointer down to the requested alignment.
+            let ptr = ptr & !(layout.align() - 1);
+            debug_assert!(
+                ptr <= new_footer as *const _ as usize,
+                "{:#x} <= {:#x}",
+                ptr,
+                new_footer as *const _ as usize
+            );
+            let ptr = NonNull::new_unchecked(ptr as *mut u8);
+            new_footer.ptr.set(ptr);
+
+            // Return a pointer to the freshly allocated region in this chunk.
+            Some(ptr)
+        }
+    }
+
+    /// Gets a new, empty chunk with room for `layout` from the global
+    /// allocator and makes it the current chunk, without allocating anything
+    /// in it.
+    fn grow_to_new_chunk(&self, layout: Layout) -> Option<NonNull<ChunkFooter>> {
+        unsafe {
             // Get a new chunk from the global allocator.
             let current_footer = self.current_chunk_footer.get();
             let current_layout = current_footer.as_ref().layout;
@@ -1463,7 +3243,14 @@ impl Bump {
             });
 
             let new_footer = sizes
-                .filter_map(|size| Bump::new_chunk(Some(size), Some(layout), Some(current_footer)))
+                .filter_map(|size| {
+                    Bump::new_chunk(
+                        Some(size),
+                        Some(layout),
+                        self.chunk_align(),
+                        Some(current_footer),
+                    )
+                })
                 .next()?;
 
             debug_assert_eq!(
@@ -1474,25 +3261,7 @@ impl Bump {
             // Set the new chunk as our new current chunk.
             self.current_chunk_footer.set(new_footer);
 
-            let new_footer = new_footer.as_ref();
-
-            // Move the bump ptr finger down to allocate room for `val`. We know
-            // this can't overflow because we successfully allocated a chunk of
-            // at least the requested size.
-            let ptr = new_footer.ptr.get().as_ptr() as usize - size;
-            // Round the pointer down to the requested alignment.
-            let ptr = ptr & !(layout.align() - 1);
-            debug_assert!(
-                ptr <= new_footer as *const _ as usize,
-                "{:#x} <= {:#x}",
-                ptr,
-                new_footer as *const _ as usize
-            );
-            let ptr = NonNull::new_unchecked(ptr as *mut u8);
-            new_footer.ptr.set(ptr);
-
-            // Return a pointer to the freshly allocated region in this chunk.
-            Some(ptr)
+            Some(new_footer)
         }
     }
 
@@ -1586,6 +3355,41 @@ impl Bump {
         }
     }
 
+    /// Returns an iterator over the raw parts of each chunk of memory that
+    /// this arena has bump allocated into.
+    ///
+    /// Unlike [`iter_allocated_chunks`](#method.iter_allocated_chunks), this
+    /// describes the whole chunk: along with where its used bytes are, each
+    /// [`RawChunk`](./struct.RawChunk.html) has the pointer and the exact
+    /// `Layout` that the chunk was allocated from the global allocator with.
+    /// Chunks are returned in the same order, most recently allocated first.
+    ///
+    /// This method only hands out raw pointers, so it is safe to call even
+    /// while there are references into the arena; reading or writing through
+    /// the pointers has the same caveats as with `iter_allocated_chunks`. The
+    /// chunks are still owned by this `Bump`, which frees them when it is
+    /// reset or dropped.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// bump.alloc_slice_fill_copy(10_000, 0u8);
+    ///
+    /// for chunk in bump.iter_allocated_chunks_raw() {
+    ///     assert!(chunk.used_len <= chunk.layout.size());
+    ///     assert_eq!(chunk.base as usize % chunk.layout.align(), 0);
+    ///     let used_end = chunk.used_start as usize + chunk.used_len;
+    ///     assert!(used_end <= chunk.base as usize + chunk.layout.size());
+    /// }
+    /// ```
+    pub fn iter_allocated_chunks_raw(&self) -> ChunkRawIter<'_> {
+        ChunkRawIter {
+            footer: Some(self.current_chunk_footer.get()),
+            bump: PhantomData,
+        }
+    }
+
     /// Calculates the number of bytes currently allocated across all chunks in
     /// this bump arena.
     ///
@@ -1623,6 +3427,297 @@ impl Bump {
         bytes
     }
 
+    /// Returns the number of chunks in this bump arena that currently hold any
+    /// allocated bytes.
+    ///
+    /// This can be less than the total number of chunks: the chunk that is
+    /// kept around by `reset` starts out empty, and so does an older chunk
+    /// that was skipped over because the first allocation made after creating
+    /// it did not fit. Allocations spilling over into many used chunks
+    /// suggest that a larger initial capacity would help.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut bump = bumpalo::Bump::new();
+    /// assert_eq!(bump.used_chunks(), 0);
+    ///
+    /// bump.alloc(1u8);
+    /// assert_eq!(bump.used_chunks(), 1);
+    ///
+    /// bump.reset();
+    /// assert_eq!(bump.used_chunks(), 0);
+    /// ```
+    pub fn used_chunks(&self) -> usize {
+        let mut footer = Some(self.current_chunk_footer.get());
+
+        let mut used = 0;
+
+        while let Some(f) = footer {
+            let foot = unsafe { f.as_ref() };
+
+            if (foot.ptr.get().as_ptr() as usize) < foot as *const _ as usize {
+                used += 1;
+            }
+
+            footer = foot.prev.get();
+        }
+
+        used
+    }
+
+    /// Returns an iterator over the `(capacity, used)` byte counts of each
+    /// chunk in this bump arena, most recently allocated chunk first.
+    ///
+    /// `capacity` is the number of bytes the chunk can hand out, as
+    /// [`chunk_capacity`](#method.chunk_capacity) reports for the current
+    /// chunk, and `used` is how many of them are currently allocated. The
+    /// `used` counts add up to
+    /// [`allocated_bytes`](#method.allocated_bytes).
+    ///
+    /// This helps to find out where an arena's memory goes, for example a huge
+    /// chunk that was created for one large allocation and is otherwise mostly
+    /// empty.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// bump.alloc(1u64);
+    ///
+    /// let usage: Vec<_> = bump.chunk_usage().collect();
+    /// assert_eq!(usage.len(), 1);
+    ///
+    /// let (capacity, used) = usage[0];
+    /// assert_eq!(capacity, bump.chunk_capacity());
+    /// assert_eq!(used, 8);
+    /// ```
+    pub fn chunk_usage(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.iter_allocated_chunks_raw().map(|chunk| {
+            let footer = chunk.used_start as usize + chunk.used_len;
+            (footer - chunk.base as usize, chunk.used_len)
+        })
+    }
+
+    /// Returns `true` if no bytes are currently allocated in this bump arena,
+    /// which is the case for a new arena and right after a `reset`, even
+    /// though the arena keeps a chunk around.
+    ///
+    /// Zero-sized allocations never take up space in the arena, so they do
+    /// not make it non-empty. Like [`allocated_bytes`](#method.allocated_bytes),
+    /// this looks at bytes in use rather than at
+    /// [`allocated_objects_count`](#method.allocated_objects_count), so it
+    /// also accounts for space given back by deallocating the most recent
+    /// allocation, and for arenas restored with `read_chunks_from`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut bump = bumpalo::Bump::new();
+    /// assert!(bump.is_empty());
+    ///
+    /// bump.alloc(());
+    /// assert!(bump.is_empty());
+    ///
+    /// bump.alloc(1u8);
+    /// assert!(!bump.is_empty());
+    ///
+    /// bump.reset();
+    /// assert!(bump.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.iter_allocated_chunks_raw()
+            .all(|chunk| chunk.used_len == 0)
+    }
+
+    /// Writes the contents of every chunk in this arena to `w`, so that it can
+    /// later be reconstructed with
+    /// [`read_chunks_from`](#method.read_chunks_from).
+    ///
+    /// Chunks are written in allocation order, least recently allocated chunk
+    /// first. Each chunk is preceded by a header with its capacity, alignment,
+    /// and the number of used bytes that follow, and only the used bytes are
+    /// written. The used bytes are written exactly as they are laid out in
+    /// memory, so offsets within a chunk are preserved.
+    ///
+    /// This method is only available with the `std` cargo feature enabled.
+    ///
+    /// ## Safety
+    ///
+    /// Every used byte of every chunk is read, so the same requirements as for
+    /// reading the chunks returned by
+    /// [`iter_allocated_chunks`](#method.iter_allocated_chunks) apply: there
+    /// must not be any padding between or within the objects allocated in this
+    /// arena, and none of them may have been dropped.
+    ///
+    /// ## Errors
+    ///
+    /// Returns any error that `w` returns.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut bump = bumpalo::Bump::new();
+    /// bump.alloc_slice_copy(b"hello");
+    ///
+    /// let mut bytes = vec![];
+    /// // Safe because we've only allocated `u8`s in this arena.
+    /// unsafe {
+    ///     bump.write_chunks_to(&mut bytes).unwrap();
+    /// }
+    ///
+    /// let mut copy = bumpalo::Bump::read_chunks_from(&bytes[..]).unwrap();
+    /// let chunk = copy.iter_allocated_chunks().next().unwrap();
+    /// let chunk: Vec<u8> = chunk.iter().map(|b| unsafe { b.assume_init() }).collect();
+    /// assert_eq!(chunk, b"hello");
+    /// ```
+    #[cfg(feature = "std")]
+    pub unsafe fn write_chunks_to<W: io::Write>(&mut self, w: &mut W) -> io::Result<()> {
+        let mut footers = std::vec::Vec::new();
+        let mut footer = Some(self.current_chunk_footer.get());
+        while let Some(f) = footer {
+            footers.push(f);
+            footer = f.as_ref().prev.get();
+        }
+
+        w.write_all(&(footers.len() as u64).to_le_bytes())?;
+
+        for f in footers.into_iter().rev() {
+            let foot = f.as_ref();
+            let ptr = foot.ptr.get().as_ptr();
+            let used = f.as_ptr() as usize - ptr as usize;
+
+            w.write_all(&((foot.layout.size() - FOOTER_SIZE) as u64).to_le_bytes())?;
+            w.write_all(&(foot.layout.align() as u64).to_le_bytes())?;
+            w.write_all(&(used as u64).to_le_bytes())?;
+            w.write_all(slice::from_raw_parts(ptr, used))?;
+        }
+
+        Ok(())
+    }
+
+    /// Constructs a new arena from chunk contents previously written by
+    /// [`write_chunks_to`](#method.write_chunks_to).
+    ///
+    /// The new arena has the same number of chunks as the original, with the
+    /// same capacities and alignments, and each chunk's used bytes are placed
+    /// at the same offsets from the end of the chunk as they were in the
+    /// original. Allocation continues in the most recently allocated chunk.
+    ///
+    /// Pointers into the original arena are of course not valid in the new
+    /// one; this is only useful for plain data or data that refers to other
+    /// data by offset.
+    ///
+    /// This method is only available with the `std` cargo feature enabled.
+    ///
+    /// ## Errors
+    ///
+    /// Returns any error that `r` returns, an error of kind
+    /// `io::ErrorKind::InvalidData` if the input is not well formed, and an
+    /// error of kind `io::ErrorKind::Other` if allocating a chunk fails.
+    #[cfg(feature = "std")]
+    pub fn read_chunks_from<R: io::Read>(mut r: R) -> io::Result<Bump> {
+        fn read_usize<R: io::Read>(r: &mut R) -> io::Result<usize> {
+            let mut bytes = [0; 8];
+            r.read_exact(&mut bytes)?;
+            let n = u64::from_le_bytes(bytes);
+            if n > usize::MAX as u64 {
+                return Err(invalid_chunk_data());
+            }
+            Ok(n as usize)
+        }
+
+        fn invalid_chunk_data() -> io::Error {
+            io::Error::new(io::ErrorKind::InvalidData, "invalid bump chunk data")
+        }
+
+        let count = read_usize(&mut r)?;
+        if count == 0 {
+            return Err(invalid_chunk_data());
+        }
+
+        let mut bump: Option<Bump> = None;
+        for _ in 0..count {
+            let capacity = read_usize(&mut r)?;
+            let align = read_usize(&mut r)?;
+            let used = read_usize(&mut r)?;
+            if used > capacity
+                || !align.is_power_of_two()
+                || capacity % CHUNK_ALIGN != 0
+                || capacity > isize::MAX as usize - OVERHEAD
+            {
+                return Err(invalid_chunk_data());
+            }
+            let align = align.max(CHUNK_ALIGN);
+            Layout::from_size_align(capacity + FOOTER_SIZE, align)
+                .map_err(|_| invalid_chunk_data())?;
+
+            // Allocate the chunk with exactly the capacity it was written
+            // with, rather than rounding it up again like a fresh chunk.
+            let prev = bump.as_ref().map(|b| b.current_chunk_footer.get());
+            let footer = Bump::new_chunk_exact(capacity, align, prev)
+                .ok_or_else(|| io::Error::from(io::ErrorKind::Other))?;
+
+            // Hand the chunk over to a `Bump` right away, so that it is freed
+            // if any of the following reads fail.
+            match &bump {
+                Some(b) => b.current_chunk_footer.set(footer),
+                None => {
+                    // Later chunks are at least as aligned as the arena's
+                    // first chunk was.
+                    bump = Some(Bump {
+                        current_chunk_footer: Cell::new(footer),
+                        allocated_objects: Cell::new(0),
+                        large_slice_threshold: usize::MAX,
+                        chunk_align_shift: align.trailing_zeros() as u8,
+                        large_slice_align_shift: 0,
+                    })
+                }
+            }
+
+            unsafe {
+                let foot = footer.as_ref();
+                let start = (footer.as_ptr() as *mut u8).sub(used);
+                ptr::write_bytes(start, 0, used);
+                r.read_exact(slice::from_raw_parts_mut(start, used))?;
+                foot.ptr.set(NonNull::new_unchecked(start));
+            }
+        }
+
+        Ok(bump.unwrap())
+    }
+
+    /// Returns the number of allocations this arena has served since it was
+    /// created or last [`reset`](#method.reset).
+    ///
+    /// Every successful allocation counts once, whatever its size, including
+    /// zero-sized allocations. Allocations made on behalf of other methods
+    /// count too: for example the buffer of a growing
+    /// [`collections::Vec`](./collections/vec/struct.Vec.html) counts each
+    /// time it has to be moved to a fresh allocation, but not when it can be
+    /// grown in place. A result that
+    /// [`alloc_try_with`](#method.alloc_try_with) or
+    /// [`try_alloc_try_with`](#method.try_alloc_try_with) rewinds after its
+    /// initializer fails does not count.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut bump = bumpalo::Bump::new();
+    /// assert_eq!(bump.allocated_objects_count(), 0);
+    ///
+    /// bump.alloc(1u8);
+    /// bump.alloc_slice_copy(&[1, 2, 3]);
+    /// bump.alloc_str("hello");
+    /// assert_eq!(bump.allocated_objects_count(), 3);
+    ///
+    /// bump.reset();
+    /// assert_eq!(bump.allocated_objects_count(), 0);
+    /// ```
+    pub fn allocated_objects_count(&self) -> usize {
+        self.allocated_objects.get()
+    }
+
     #[inline]
     unsafe fn is_last_allocation(&self, ptr: NonNull<u8>) -> bool {
         let footer = self.current_chunk_footer.get();
@@ -1648,25 +3743,30 @@ impl Bump {
         new_size: usize,
     ) -> Result<NonNull<u8>, alloc::AllocErr> {
         let old_size = layout.size();
-        if self.is_last_allocation(ptr)
-                // Only reclaim the excess space (which requires a copy) if it
-                // is worth it: we are actually going to recover "enough" space
-                // and we can do a non-overlapping copy.
-                && new_size <= old_size / 2
-        {
-            let delta = old_size - new_size;
+        if self.is_last_allocation(ptr) {
+            // The allocation is at the bottom of the used region, so its
+            // excess space is at its end, away from the bump pointer. Move the
+            // contents up by the excess and rewind the bump pointer to reclaim
+            // it. Only whole multiples of the alignment can be reclaimed, or
+            // the moved contents would be misaligned.
+            let delta = (old_size - new_size) & !(layout.align() - 1);
+            if delta == 0 {
+                return Ok(ptr);
+            }
             let footer = self.current_chunk_footer.get();
             let footer = footer.as_ref();
             footer
                 .ptr
                 .set(NonNull::new_unchecked(footer.ptr.get().as_ptr().add(delta)));
             let new_ptr = footer.ptr.get();
-            // NB: we know it is non-overlapping because of the size check
-            // in the `if` condition.
-            ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr(), new_size);
-            return Ok(new_ptr);
+            if new_size <= delta {
+                ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr(), new_size);
+            } else {
+                ptr::copy(ptr.as_ptr(), new_ptr.as_ptr(), new_size);
+            }
+            Ok(new_ptr)
         } else {
-            return Ok(ptr);
+            Ok(ptr)
         }
     }
 
@@ -1739,6 +3839,107 @@ impl<'a> Iterator for ChunkIter<'a> {
 
 impl<'a> iter::FusedIterator for ChunkIter<'a> {}
 
+/// The raw parts of one chunk of memory that an arena has bump allocated into.
+///
+/// This struct is created by the [`iter_allocated_chunks_raw`] method on
+/// [`Bump`].
+///
+/// [`Bump`]: ./struct.Bump.html
+/// [`iter_allocated_chunks_raw`]: ./struct.Bump.html#method.iter_allocated_chunks_raw
+#[derive(Clone, Copy, Debug)]
+pub struct RawChunk {
+    /// The start of the chunk's allocation, as returned by the global
+    /// allocator.
+    pub base: *mut u8,
+
+    /// The layout that the chunk was allocated from the global allocator
+    /// with. This includes the space that bumpalo reserves at the end of each
+    /// chunk for its own bookkeeping.
+    pub layout: Layout,
+
+    /// The start of the chunk's used bytes, i.e. the most recent allocation
+    /// made in this chunk.
+    pub used_start: *mut u8,
+
+    /// The number of used bytes in this chunk, starting at `used_start`.
+    pub used_len: usize,
+}
+
+/// An iterator over the raw parts of each chunk of memory that an arena has
+/// bump allocated into.
+///
+/// The chunks are returned ordered by allocation time, with the most recently
+/// allocated chunk being returned first.
+///
+/// This struct is created by the [`iter_allocated_chunks_raw`] method on
+/// [`Bump`].
+///
+/// [`Bump`]: ./struct.Bump.html
+/// [`iter_allocated_chunks_raw`]: ./struct.Bump.html#method.iter_allocated_chunks_raw
+#[derive(Debug)]
+pub struct ChunkRawIter<'a> {
+    footer: Option<NonNull<ChunkFooter>>,
+    bump: PhantomData<&'a Bump>,
+}
+
+impl<'a> Iterator for ChunkRawIter<'a> {
+    type Item = RawChunk;
+    fn next(&mut self) -> Option<RawChunk> {
+        unsafe {
+            let foot = self.footer?;
+            let foot = foot.as_ref();
+            let ptr = foot.ptr.get().as_ptr();
+            debug_assert!(foot.data.as_ptr() <= ptr);
+            debug_assert!(ptr as usize <= foot as *const _ as usize);
+
+            self.footer = foot.prev.get();
+            Some(RawChunk {
+                base: foot.data.as_ptr(),
+                layout: foot.layout,
+                used_start: ptr,
+                used_len: foot as *const _ as usize - ptr as usize,
+            })
+        }
+    }
+}
+
+impl<'a> iter::FusedIterator for ChunkRawIter<'a> {}
+
+/// Initializes the `len` elements starting at `dst` by calling `f` with each
+/// index in order. If `f` panics, the elements written so far are dropped.
+///
+/// `dst` must be valid for writing `len` elements of `T`.
+#[inline(always)]
+unsafe fn fill_slice_with<'a, T, F>(dst: NonNull<T>, len: usize, mut f: F) -> &'a mut [T]
+where
+    F: FnMut(usize) -> T,
+{
+    struct DropPrefix<T> {
+        start: *mut T,
+        len: usize,
+    }
+
+    impl<T> Drop for DropPrefix<T> {
+        fn drop(&mut self) {
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.start, self.len));
+            }
+        }
+    }
+
+    let mut prefix = DropPrefix {
+        start: dst.as_ptr(),
+        len: 0,
+    };
+    for i in 0..len {
+        ptr::write(dst.as_ptr().add(i), f(i));
+        prefix.len += 1;
+    }
+    mem::forget(prefix);
+
+    slice::from_raw_parts_mut(dst.as_ptr(), len)
+}
+
 #[inline(never)]
 #[cold]
 fn oom() -> ! {
@@ -1770,15 +3971,18 @@ unsafe impl<'a> alloc::Alloc for &'a Bump {
         }
 
         if new_size <= old_size {
-            self.shrink(ptr, layout, new_size)
+            Bump::shrink(self, ptr, layout, new_size)
         } else {
-            self.grow(ptr, layout, new_size)
+            Bump::grow(self, ptr, layout, new_size)
         }
     }
 }
 
+// `&Bump` implements `Allocator` too, through the standard library's blanket
+// implementation for references, so collections can either borrow or own
+// their arena.
 #[cfg(feature = "allocator_api")]
-unsafe impl<'a> Allocator for &'a Bump {
+unsafe impl Allocator for Bump {
     fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
         self.try_alloc_layout(layout)
             .map(|p| NonNull::slice_from_raw_parts(p, layout.size()))
@@ -1796,6 +4000,12 @@ unsafe impl<'a> Allocator for &'a Bump {
         new_layout: Layout,
     ) -> Result<NonNull<[u8]>, AllocError> {
         let new_size = new_layout.size();
+        if new_layout.align() > old_layout.align() {
+            // Rewinding the bump pointer only preserves the old alignment.
+            let new_ptr = self.allocate(new_layout)?;
+            ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr() as *mut u8, new_size);
+            return Ok(new_ptr);
+        }
         Bump::shrink(self, ptr, old_layout, new_size)
             .map(|p| NonNull::slice_from_raw_parts(p, new_size))
             .map_err(|_| AllocError)
@@ -1819,7 +4029,7 @@ unsafe impl<'a> Allocator for &'a Bump {
         old_layout: Layout,
         new_layout: Layout,
     ) -> Result<NonNull<[u8]>, AllocError> {
-        let mut ptr = self.grow(ptr, old_layout, new_layout)?;
+        let mut ptr = Allocator::grow(self, ptr, old_layout, new_layout)?;
         ptr.as_mut()[old_layout.size()..].fill(0);
         Ok(ptr)
     }
@@ -1843,18 +4053,39 @@ mod tests {
             const CAPACITY: usize = 1024 - OVERHEAD;
             let mut b = Bump::with_capacity(CAPACITY);
 
-            // `realloc` doesn't shrink allocations that aren't "worth it".
+            // `realloc` will shrink the last allocation, moving its contents
+            // even when the old and new locations overlap.
             let layout = Layout::from_size_align(100, 1).unwrap();
             let p = b.alloc_layout(layout);
+            for i in 0..100 {
+                *p.as_ptr().add(i) = i as u8;
+            }
             let q = (&b).realloc(p, layout, 51).unwrap();
-            assert_eq!(p, q);
+            assert_eq!(q.as_ptr() as usize, p.as_ptr() as usize + 49);
+            assert_eq!(
+                slice::from_raw_parts(q.as_ptr(), 51),
+                &(0..51).collect::<core_alloc::vec::Vec<u8>>()[..]
+            );
             b.reset();
 
-            // `realloc` will shrink allocations that are "worth it".
+            // `realloc` will shrink the last allocation without overlap, too.
             let layout = Layout::from_size_align(100, 1).unwrap();
             let p = b.alloc_layout(layout);
             let q = (&b).realloc(p, layout, 50).unwrap();
-            assert!(p != q);
+            assert_eq!(q.as_ptr() as usize, p.as_ptr() as usize + 50);
+            b.reset();
+
+            // `realloc` only reclaims whole multiples of the alignment when
+            // shrinking.
+            let layout = Layout::from_size_align(64, 16).unwrap();
+            let p = b.alloc_layout(layout);
+            let q = (&b).realloc(p, layout, 40).unwrap();
+            assert_eq!(q.as_ptr() as usize, p.as_ptr() as usize + 16);
+            assert_eq!(q.as_ptr() as usize % 16, 0);
+            let q = (&b)
+                .realloc(q, Layout::from_size_align(48, 16).unwrap(), 40)
+                .unwrap();
+            assert_eq!(q.as_ptr() as usize, p.as_ptr() as usize + 16);
             b.reset();
 
             // `realloc` will reuse the last allocation when growing.
@@ -0,0 +1,172 @@
+use crate::alloc::AllocErr;
+use crate::Bump;
+use core::cell::RefCell;
+use core::fmt;
+use core::mem::ManuallyDrop;
+use core::ops::{Deref, DerefMut};
+use core_alloc::vec::Vec;
+
+/// A pool of `Bump` arenas that are reused instead of freed, so that their
+/// chunks are recycled across many short-lived arenas.
+///
+/// [`get`](#method.get) hands out a [`PooledBump`](./struct.PooledBump.html)
+/// guard that can be used anywhere a `&Bump` is expected. When the guard is
+/// dropped, its arena is reset and goes back into the pool, where it waits to
+/// be handed out again. Resetting keeps the arena's most recently allocated
+/// chunk, which is also its largest, so a pooled arena that has grown once
+/// will not need to grow again for a similar workload.
+///
+/// This fits the per-request arena pattern: take an arena from the pool at the
+/// start of each request, allocate freely while handling it, and let it return
+/// to the pool when the request is done.
+///
+/// ## Example
+///
+/// ```
+/// use bumpalo::ChunkPool;
+///
+/// let pool = ChunkPool::new();
+///
+/// for request in 0..10 {
+///     let bump = pool.get();
+///     let message = bump.alloc_str("handling request");
+///     let id = bump.alloc(request);
+///     assert_eq!(message, "handling request");
+///     assert_eq!(*id, request);
+/// }
+///
+/// // Only one arena was ever created, and it is back in the pool.
+/// assert_eq!(pool.idle(), 1);
+/// ```
+pub struct ChunkPool {
+    idle: RefCell<Vec<Bump>>,
+    chunk_capacity: usize,
+}
+
+impl ChunkPool {
+    /// Constructs a new, empty pool. Arenas are created on demand with
+    /// `Bump::new`.
+    pub fn new() -> ChunkPool {
+        ChunkPool::with_chunk_capacity(0)
+    }
+
+    /// Constructs a new, empty pool whose arenas are created on demand with
+    /// `Bump::with_capacity(chunk_capacity)`.
+    pub fn with_chunk_capacity(chunk_capacity: usize) -> ChunkPool {
+        ChunkPool {
+            idle: RefCell::new(Vec::new()),
+            chunk_capacity,
+        }
+    }
+
+    /// Takes an arena out of the pool, creating a new one if the pool is
+    /// empty. The arena is returned to the pool when the guard is dropped.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if a new arena is needed and allocating its first chunk fails.
+    pub fn get(&self) -> PooledBump<'_> {
+        self.try_get().unwrap_or_else(|_| crate::oom())
+    }
+
+    /// Like `get`, but returns an `Err` instead of panicking if a new arena is
+    /// needed and allocating its first chunk fails.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if a new arena is needed and allocating its first chunk fails.
+    pub fn try_get(&self) -> Result<PooledBump<'_>, AllocErr> {
+        let bump = match self.idle.borrow_mut().pop() {
+            Some(bump) => bump,
+            None => Bump::try_with_capacity(self.chunk_capacity)?,
+        };
+        Ok(PooledBump {
+            bump: ManuallyDrop::new(bump),
+            pool: self,
+        })
+    }
+
+    /// Returns the number of arenas currently waiting in the pool.
+    pub fn idle(&self) -> usize {
+        self.idle.borrow().len()
+    }
+
+    /// Frees every arena currently waiting in the pool. Arenas that are handed
+    /// out return to the pool as usual.
+    pub fn clear(&mut self) {
+        self.idle.get_mut().clear();
+    }
+}
+
+impl Default for ChunkPool {
+    fn default() -> ChunkPool {
+        ChunkPool::new()
+    }
+}
+
+impl fmt::Debug for ChunkPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkPool")
+            .field("idle", &self.idle())
+            .field("chunk_capacity", &self.chunk_capacity)
+            .finish()
+    }
+}
+
+/// An arena taken from a [`ChunkPool`](./struct.ChunkPool.html), which resets
+/// it and returns it to the pool when dropped.
+///
+/// `PooledBump` dereferences to `Bump`, so it can be used anywhere a `&Bump` is
+/// expected.
+///
+/// As with `Bump::reset`, dropping the guard does not run any `Drop`
+/// implementations on the values allocated in the arena.
+///
+/// A large slice alignment set through the guard with
+/// [`set_large_slice_alignment`](./struct.Bump.html#method.set_large_slice_alignment)
+/// is turned back off when the arena returns to the pool, so it never carries
+/// over to the next user.
+pub struct PooledBump<'pool> {
+    bump: ManuallyDrop<Bump>,
+    pool: &'pool ChunkPool,
+}
+
+impl<'pool> PooledBump<'pool> {
+    /// Returns the pool that this arena will return to.
+    pub fn pool(&self) -> &'pool ChunkPool {
+        self.pool
+    }
+}
+
+impl<'pool> Deref for PooledBump<'pool> {
+    type Target = Bump;
+
+    fn deref(&self) -> &Bump {
+        &self.bump
+    }
+}
+
+impl<'pool> DerefMut for PooledBump<'pool> {
+    fn deref_mut(&mut self) -> &mut Bump {
+        &mut self.bump
+    }
+}
+
+impl<'pool> Drop for PooledBump<'pool> {
+    fn drop(&mut self) {
+        // Safe because the arena is never touched again after it is taken
+        // out here.
+        let mut bump = unsafe { ManuallyDrop::take(&mut self.bump) };
+        bump.reset();
+        bump.set_large_slice_alignment(1, usize::MAX);
+        self.pool.idle.borrow_mut().push(bump);
+    }
+}
+
+impl<'pool> fmt::Debug for PooledBump<'pool> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PooledBump")
+            .field("bump", &*self.bump)
+            .finish()
+    }
+}
@@ -0,0 +1,165 @@
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::Index;
+use core::slice;
+
+/// A read-only slice that is stored in several contiguous segments, possibly
+/// spread over several of an arena's chunks.
+///
+/// This is created by
+/// [`Bump::alloc_slice_copy_chunked`](./struct.Bump.html#method.alloc_slice_copy_chunked).
+/// The segments appear in order, so iterating over each of them in turn visits
+/// the elements in the same order as the original slice.
+///
+/// As there are only ever a few segments, typically one per chunk, indexing
+/// and iteration cost little more than they do for a contiguous slice.
+pub struct SegmentedSlice<'bump, T> {
+    segments: &'bump [&'bump [T]],
+    len: usize,
+}
+
+impl<'bump, T> SegmentedSlice<'bump, T> {
+    pub(crate) fn new(segments: &'bump [&'bump [T]], len: usize) -> SegmentedSlice<'bump, T> {
+        debug_assert_eq!(segments.iter().map(|s| s.len()).sum::<usize>(), len);
+        SegmentedSlice { segments, len }
+    }
+
+    /// Returns the number of elements, across all segments.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the element at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, mut index: usize) -> Option<&'bump T> {
+        for segment in self.segments {
+            if index < segment.len() {
+                return Some(&segment[index]);
+            }
+            index -= segment.len();
+        }
+        None
+    }
+
+    /// Returns the contiguous segments that make up this slice, in order.
+    pub fn segments(&self) -> &'bump [&'bump [T]] {
+        self.segments
+    }
+
+    /// Returns an iterator over the elements, in order.
+    pub fn iter(&self) -> SegmentedSliceIter<'bump, T> {
+        SegmentedSliceIter {
+            segment: [].iter(),
+            rest: self.segments.iter(),
+        }
+    }
+}
+
+impl<'bump, T> Clone for SegmentedSlice<'bump, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'bump, T> Copy for SegmentedSlice<'bump, T> {}
+
+impl<'bump, T> Index<usize> for SegmentedSlice<'bump, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Some(x) => x,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.len, index
+            ),
+        }
+    }
+}
+
+impl<'bump, T> IntoIterator for SegmentedSlice<'bump, T> {
+    type Item = &'bump T;
+    type IntoIter = SegmentedSliceIter<'bump, T>;
+
+    fn into_iter(self) -> SegmentedSliceIter<'bump, T> {
+        self.iter()
+    }
+}
+
+impl<'bump, T> IntoIterator for &SegmentedSlice<'bump, T> {
+    type Item = &'bump T;
+    type IntoIter = SegmentedSliceIter<'bump, T>;
+
+    fn into_iter(self) -> SegmentedSliceIter<'bump, T> {
+        self.iter()
+    }
+}
+
+impl<'bump, T: fmt::Debug> fmt::Debug for SegmentedSlice<'bump, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// An iterator over the elements of a
+/// [`SegmentedSlice`](./struct.SegmentedSlice.html).
+///
+/// This struct is created by the [`iter`](./struct.SegmentedSlice.html#method.iter)
+/// method on `SegmentedSlice`.
+pub struct SegmentedSliceIter<'bump, T> {
+    segment: slice::Iter<'bump, T>,
+    rest: slice::Iter<'bump, &'bump [T]>,
+}
+
+impl<'bump, T> Iterator for SegmentedSliceIter<'bump, T> {
+    type Item = &'bump T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'bump T> {
+        loop {
+            if let Some(x) = self.segment.next() {
+                return Some(x);
+            }
+            self.segment = self.rest.next()?.iter();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.segment.len() + self.rest.as_slice().iter().map(|s| s.len()).sum::<usize>();
+        (len, Some(len))
+    }
+
+    // Iterate over each segment as a plain slice, which the optimizer handles
+    // much better than the segment switching in `next`.
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &'bump T) -> B,
+    {
+        let acc = self.segment.fold(init, &mut f);
+        self.rest
+            .fold(acc, |acc, segment| segment.iter().fold(acc, &mut f))
+    }
+}
+
+impl<'bump, T> ExactSizeIterator for SegmentedSliceIter<'bump, T> {}
+
+impl<'bump, T> FusedIterator for SegmentedSliceIter<'bump, T> {}
+
+impl<'bump, T> Clone for SegmentedSliceIter<'bump, T> {
+    fn clone(&self) -> Self {
+        SegmentedSliceIter {
+            segment: self.segment.clone(),
+            rest: self.rest.clone(),
+        }
+    }
+}
+
+impl<'bump, T: fmt::Debug> fmt::Debug for SegmentedSliceIter<'bump, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
@@ -0,0 +1,165 @@
+use crate::alloc::AllocErr;
+use crate::Bump;
+use core::alloc::Layout;
+use core::fmt;
+use core::ptr::{self, NonNull};
+use core::slice;
+use core::str;
+
+/// A `fmt::Write` sink that formats into a single, fixed-size allocation in a
+/// `Bump` arena.
+///
+/// The buffer is reserved once, up front, when the writer is created. Writing
+/// never grows it or allocates anything else: output that does not fit in the
+/// remaining capacity is cut off at the last `char` boundary that fits, the
+/// writer is marked as truncated, and the write returns `fmt::Error` so that
+/// formatting stops early. Every write after a truncation is rejected as well,
+/// so the finished string is always a prefix of the full output.
+///
+/// Use [`collections::String`](./collections/struct.String.html) instead when
+/// the output should grow to fit.
+///
+/// ## Example
+///
+/// ```
+/// use bumpalo::{ArenaWriter, Bump};
+/// use std::fmt::Write;
+///
+/// let bump = Bump::new();
+///
+/// let mut w = ArenaWriter::with_capacity_in(16, &bump);
+/// write!(w, "{} + {} = {}", 1, 2, 1 + 2).unwrap();
+/// assert_eq!(w.finish(), ("1 + 2 = 3", false));
+///
+/// let mut w = ArenaWriter::with_capacity_in(8, &bump);
+/// assert!(write!(w, "hello, {}!", "world").is_err());
+/// assert_eq!(w.finish(), ("hello, w", true));
+/// ```
+pub struct ArenaWriter<'bump> {
+    ptr: NonNull<u8>,
+    capacity: usize,
+    len: usize,
+    truncated: bool,
+    bump: &'bump Bump,
+}
+
+impl<'bump> ArenaWriter<'bump> {
+    /// Creates a writer that can hold up to `capacity` bytes of output,
+    /// reserving all of them in `bump` immediately.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for `capacity` bytes fails.
+    pub fn with_capacity_in(capacity: usize, bump: &'bump Bump) -> ArenaWriter<'bump> {
+        ArenaWriter::try_with_capacity_in(capacity, bump).unwrap_or_else(|_| crate::oom())
+    }
+
+    /// Like `with_capacity_in`, but returns an `Err` instead of panicking if
+    /// reserving space for `capacity` bytes fails.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if reserving space for `capacity` bytes fails.
+    pub fn try_with_capacity_in(
+        capacity: usize,
+        bump: &'bump Bump,
+    ) -> Result<ArenaWriter<'bump>, AllocErr> {
+        let layout = Layout::from_size_align(capacity, 1).map_err(|_| AllocErr)?;
+        let ptr = bump.try_alloc_layout(layout)?;
+        Ok(ArenaWriter {
+            ptr,
+            capacity,
+            len: 0,
+            truncated: false,
+            bump,
+        })
+    }
+
+    /// Returns the output written so far.
+    pub fn as_str(&self) -> &str {
+        // Safe because the first `len` bytes are initialized and only ever
+        // written from whole `char`s.
+        unsafe { str::from_utf8_unchecked(slice::from_raw_parts(self.ptr.as_ptr(), self.len)) }
+    }
+
+    /// Returns the number of bytes written so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing has been written yet.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the total number of bytes this writer can hold.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of bytes that can still be written.
+    pub fn remaining(&self) -> usize {
+        self.capacity - self.len
+    }
+
+    /// Returns `true` if some output did not fit and was dropped.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns the `Bump` arena that this writer's buffer was allocated in.
+    pub fn bump(&self) -> &'bump Bump {
+        self.bump
+    }
+
+    /// Consumes the writer, returning the written output along with whether it
+    /// was truncated.
+    ///
+    /// The returned string lives as long as the arena. Any unused capacity
+    /// stays reserved in the arena.
+    pub fn finish(self) -> (&'bump str, bool) {
+        // Safe for the same reasons as `as_str`, and because the buffer is
+        // never touched again once the writer is consumed.
+        let s =
+            unsafe { str::from_utf8_unchecked(slice::from_raw_parts(self.ptr.as_ptr(), self.len)) };
+        (s, self.truncated)
+    }
+}
+
+impl<'bump> fmt::Write for ArenaWriter<'bump> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.truncated {
+            return Err(fmt::Error);
+        }
+
+        let mut n = s.len();
+        if n > self.remaining() {
+            n = self.remaining();
+            while !s.is_char_boundary(n) {
+                n -= 1;
+            }
+            self.truncated = true;
+        }
+
+        unsafe {
+            ptr::copy_nonoverlapping(s.as_ptr(), self.ptr.as_ptr().add(self.len), n);
+        }
+        self.len += n;
+
+        if self.truncated {
+            Err(fmt::Error)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'bump> fmt::Debug for ArenaWriter<'bump> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArenaWriter")
+            .field("contents", &self.as_str())
+            .field("capacity", &self.capacity)
+            .field("truncated", &self.truncated)
+            .finish()
+    }
+}
@@ -0,0 +1,112 @@
+use bumpalo::{alloc_in_place, Bump};
+
+#[derive(Debug, PartialEq)]
+struct Small {
+    a: u8,
+    b: u64,
+    c: Option<String>,
+}
+
+#[test]
+fn alloc_in_place_writes_every_field() {
+    let b = Bump::new();
+    let s = alloc_in_place!(in &b; Small {
+        c: Some("hello".to_string()),
+        a: 1,
+        b: 2,
+    });
+    assert_eq!(
+        *s,
+        Small {
+            a: 1,
+            b: 2,
+            c: Some("hello".to_string()),
+        }
+    );
+    assert_eq!(s as *mut _ as usize % std::mem::align_of::<Small>(), 0);
+    assert_eq!(b.allocated_objects_count(), 1);
+}
+
+#[test]
+fn alloc_in_place_evaluates_fields_in_order() {
+    let b = Bump::new();
+    let mut order = vec![];
+    let s = alloc_in_place!(in &b; Small {
+        b: { order.push('b'); 2 },
+        c: { order.push('c'); None },
+        a: { order.push('a'); 1 },
+    });
+    assert_eq!(order, ['b', 'c', 'a']);
+    assert_eq!((s.a, s.b, s.c.as_ref()), (1, 2, None));
+}
+
+struct Generic<T> {
+    value: T,
+    count: usize,
+}
+
+#[test]
+fn alloc_in_place_generic() {
+    let b = Bump::new();
+    let g = alloc_in_place!(in &b; Generic<&str> { value: "x", count: 3 });
+    assert_eq!((g.value, g.count), ("x", 3));
+}
+
+#[repr(C, packed)]
+struct Packed {
+    a: u8,
+    b: u32,
+}
+
+#[test]
+fn alloc_in_place_packed() {
+    let b = Bump::new();
+    let p = alloc_in_place!(in &b; Packed { a: 1, b: 0xdead_beef });
+    let (a, b) = (p.a, p.b);
+    assert_eq!((a, b), (1, 0xdead_beef));
+}
+
+const FIELD: usize = 64 * 1024;
+
+struct Huge {
+    f0: [u8; FIELD],
+    f1: [u8; FIELD],
+    f2: [u8; FIELD],
+    f3: [u8; FIELD],
+    f4: [u8; FIELD],
+    f5: [u8; FIELD],
+    f6: [u8; FIELD],
+    f7: [u8; FIELD],
+}
+
+// With optimizations, only about one field at a time is on the stack, so the
+// struct is built with a stack half its size. Without optimizations every
+// field's temporary gets its own stack slot, and how many slots there are
+// depends on the compiler, so this only runs in release mode. See the debug
+// caveat in `alloc_in_place!`'s docs.
+const STACK: usize = 4 * FIELD;
+
+#[test]
+#[cfg_attr(debug_assertions, ignore)]
+fn alloc_in_place_larger_than_stack() {
+    std::thread::Builder::new()
+        .stack_size(STACK)
+        .spawn(|| {
+            let b = Bump::new();
+            let h = alloc_in_place!(in &b; Huge {
+                f0: [0; FIELD],
+                f1: [1; FIELD],
+                f2: [2; FIELD],
+                f3: [3; FIELD],
+                f4: [4; FIELD],
+                f5: [5; FIELD],
+                f6: [6; FIELD],
+                f7: [7; FIELD],
+            });
+            assert!(h.f0.iter().all(|&x| x == 0));
+            assert!(h.f7.iter().all(|&x| x == 7));
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
@@ -117,3 +117,35 @@ fn alloc_try_with_large_enum_err() {
         .alloc_try_with(|| Result::<LargeEnum, _>::Err(()))
         .is_err());
 }
+
+#[test]
+fn alloc_try_with_large_err_in_a_loop_does_not_grow() {
+    let b = Bump::new();
+    b.alloc(1u8);
+    let before = b.allocated_bytes();
+    let objects_before = b.allocated_objects_count();
+
+    // Larger than the first chunk, so each attempt needs a new one, but small
+    // enough to live on the stack of a debug build.
+    for _ in 0..10 {
+        assert!(b
+            .alloc_try_with(|| Result::<[u8; 64 * 1024], _>::Err(()))
+            .is_err());
+        assert_eq!(b.allocated_bytes(), before);
+        assert_eq!(b.allocated_objects_count(), objects_before);
+    }
+}
+
+#[test]
+fn alloc_try_with_err_rewinds_padding() {
+    let b = Bump::new();
+    b.alloc(1u8);
+    let before = b.allocated_bytes();
+
+    for _ in 0..100 {
+        assert!(b
+            .alloc_try_with(|| Result::<[u64; 64], _>::Err(()))
+            .is_err());
+        assert_eq!(b.allocated_bytes(), before);
+    }
+}
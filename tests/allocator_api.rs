@@ -114,3 +114,39 @@ fn allocator_grow_zeroed() {
     assert!(bump.allocated_bytes() <= allocated * 2);
     assert_eq!(unsafe { p.as_ref() }, [42, 42, 42, 42, 0, 0, 0, 0]);
 }
+
+fn owned_vec(n: u32) -> Vec<u32, Bump> {
+    let mut v = Vec::new_in(Bump::new());
+    v.extend(0..n);
+    v
+}
+
+#[test]
+fn allocator_owned_bump() {
+    let mut v = owned_vec(1000);
+    assert_eq!(v.len(), 1000);
+    assert!(v.iter().copied().eq(0..1000));
+
+    // The vector's buffer lives in the arena it owns.
+    let bump = v.allocator();
+    assert!(bump.allocated_bytes() >= 1000 * std::mem::size_of::<u32>());
+
+    v.truncate(10);
+    v.shrink_to_fit();
+    v.push(10);
+    assert!(v.iter().copied().eq(0..11));
+}
+
+#[test]
+fn allocator_shrink_to_stricter_alignment() {
+    let bump = Bump::new();
+    let old_layout = Layout::from_size_align(64, 1).unwrap();
+    let new_layout = Layout::from_size_align(32, 32).unwrap();
+    bump.alloc(0u8);
+    let p = bump.allocate(old_layout).unwrap().cast::<u8>();
+    unsafe { p.as_ptr().write_bytes(7, 64) };
+
+    let q = unsafe { bump.shrink(p, old_layout, new_layout) }.unwrap();
+    assert_eq!(q.cast::<u8>().as_ptr() as usize % 32, 0);
+    assert_eq!(unsafe { q.as_ref() }, [7; 32]);
+}
@@ -0,0 +1,146 @@
+#![cfg(feature = "std")]
+
+use bumpalo::Bump;
+use std::io;
+
+fn chunks(b: &mut Bump) -> Vec<(usize, Vec<u8>)> {
+    b.iter_allocated_chunks()
+        .map(|c| {
+            let bytes = c.iter().map(|b| unsafe { b.assume_init() }).collect();
+            (c.as_ptr() as usize, bytes)
+        })
+        .collect()
+}
+
+#[test]
+fn round_trip_preserves_chunk_layout() {
+    let mut b = Bump::new();
+    for i in 0..10_000u32 {
+        b.alloc(i);
+    }
+    assert!(b.iter_allocated_chunks().count() > 1);
+
+    let mut bytes = vec![];
+    unsafe {
+        b.write_chunks_to(&mut bytes).unwrap();
+    }
+
+    let mut copy = Bump::read_chunks_from(&bytes[..]).unwrap();
+    let original = chunks(&mut b);
+    let restored = chunks(&mut copy);
+
+    assert_eq!(original.len(), restored.len());
+    for ((_, a), (_, b)) in original.iter().zip(&restored) {
+        assert_eq!(a, b);
+    }
+    assert_eq!(b.allocated_bytes(), copy.allocated_bytes());
+
+    // The restored arena keeps allocating in its most recent chunk.
+    let next = copy.alloc(7u32) as *const u32 as usize;
+    let (newest_start, _) = restored[0];
+    assert_eq!(next, newest_start - 4);
+}
+
+#[test]
+fn round_trip_preserves_chunk_usage() {
+    for &align in &[1, 4096] {
+        let mut b = Bump::with_chunk_alignment(align);
+        for i in 0..10_000u32 {
+            b.alloc(i);
+        }
+        let original: Vec<_> = b.chunk_usage().collect();
+        assert!(original.len() > 2);
+
+        let mut bytes = vec![];
+        unsafe {
+            b.write_chunks_to(&mut bytes).unwrap();
+        }
+
+        // Every chunk comes back with exactly the same capacity and number of
+        // used bytes, rather than being resized like a fresh chunk.
+        let copy = Bump::read_chunks_from(&bytes[..]).unwrap();
+        assert_eq!(copy.chunk_usage().collect::<Vec<_>>(), original);
+    }
+}
+
+#[test]
+fn round_trip_empty_arena() {
+    let mut b = Bump::new();
+    let mut bytes = vec![];
+    unsafe {
+        b.write_chunks_to(&mut bytes).unwrap();
+    }
+
+    let mut copy = Bump::read_chunks_from(&bytes[..]).unwrap();
+    assert_eq!(copy.allocated_bytes(), 0);
+    assert_eq!(copy.iter_allocated_chunks().count(), 1);
+}
+
+#[test]
+fn read_rejects_bad_input() {
+    let mut b = Bump::new();
+    b.alloc_slice_copy(b"hello, world");
+    let mut bytes = vec![];
+    unsafe {
+        b.write_chunks_to(&mut bytes).unwrap();
+    }
+
+    // Truncated input.
+    let err = Bump::read_chunks_from(&bytes[..bytes.len() - 1]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+    // No chunks at all.
+    let err = Bump::read_chunks_from(&0u64.to_le_bytes()[..]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    // More used bytes than the chunk can hold.
+    let mut bad = bytes.clone();
+    bad[24..32].copy_from_slice(&u64::MAX.to_le_bytes());
+    let err = Bump::read_chunks_from(&bad[..]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn round_trip_preserves_chunk_alignment() {
+    let mut b = Bump::with_chunk_alignment(4096);
+    b.alloc(1u8);
+    let mut bytes = vec![];
+    unsafe {
+        b.write_chunks_to(&mut bytes).unwrap();
+    }
+
+    let copy = Bump::read_chunks_from(&bytes[..]).unwrap();
+    // The restored chunk has the same capacity, not one rounded up for its
+    // alignment.
+    assert_eq!(copy.chunk_capacity(), b.chunk_capacity());
+    assert_eq!(
+        copy.chunk_usage().collect::<Vec<_>>(),
+        b.chunk_usage().collect::<Vec<_>>()
+    );
+    for _ in 0..4 {
+        // The current chunk always has exactly one byte used in it here.
+        let rest = copy.chunk_capacity() - 1;
+        let p = copy.alloc_slice_fill_copy(rest, 0u8).as_ptr() as usize;
+        assert_eq!(p % 4096, 0);
+        copy.alloc(0u8);
+    }
+}
+
+#[test]
+fn round_trip_preserves_page_rounded_capacity() {
+    let mut b = Bump::with_capacity_page_rounded(5000);
+    b.alloc_slice_fill_copy(100, 1u8);
+    assert_eq!(b.chunk_usage().collect::<Vec<_>>(), [(8192, 100)]);
+
+    let mut bytes = vec![];
+    unsafe {
+        b.write_chunks_to(&mut bytes).unwrap();
+    }
+
+    let copy = Bump::read_chunks_from(&bytes[..]).unwrap();
+    assert_eq!(copy.chunk_usage().collect::<Vec<_>>(), [(8192, 100)]);
+
+    // The rest of the capacity is still there to fill exactly.
+    copy.alloc_slice_fill_copy(8092, 0u8);
+    assert_eq!(copy.chunk_usage().collect::<Vec<_>>(), [(8192, 8192)]);
+}
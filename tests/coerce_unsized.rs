@@ -0,0 +1,43 @@
+#![cfg(all(feature = "boxed", feature = "coerce_unsized"))]
+use bumpalo::{boxed::Box, Bump};
+use std::cell::Cell;
+use std::fmt::Display;
+
+#[test]
+fn coerce_to_trait_object() {
+    let bump = Bump::new();
+    let b: Box<dyn Display> = Box::new_in(42, &bump);
+    assert_eq!(b.to_string(), "42");
+
+    let items: Vec<Box<dyn Display>> = vec![Box::new_in(1, &bump), Box::new_in("two", &bump)];
+    let strings: Vec<String> = items.iter().map(|b| b.to_string()).collect();
+    assert_eq!(strings, ["1", "two"]);
+}
+
+#[test]
+fn coerce_to_slice() {
+    let bump = Bump::new();
+    let b: Box<[u32]> = Box::new_in([1, 2, 3], &bump);
+    assert_eq!(&*b, [1, 2, 3]);
+}
+
+#[test]
+fn coerced_box_runs_drop() {
+    struct SetOnDrop<'a>(&'a Cell<bool>);
+
+    impl Drop for SetOnDrop<'_> {
+        fn drop(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    trait Marker {}
+    impl Marker for SetOnDrop<'_> {}
+
+    let dropped = Cell::new(false);
+    let bump = Bump::new();
+    let b: Box<dyn Marker> = Box::new_in(SetOnDrop(&dropped), &bump);
+    assert!(!dropped.get());
+    drop(b);
+    assert!(dropped.get());
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "std")]
+
+use bumpalo::Bump;
+use std::ffi::CStr;
+
+unsafe fn read_all(argv: *const *const std::os::raw::c_char) -> Vec<String> {
+    let mut strs = vec![];
+    let mut p = argv;
+    while !(*p).is_null() {
+        strs.push(CStr::from_ptr(*p).to_str().unwrap().to_string());
+        p = p.add(1);
+    }
+    strs
+}
+
+#[test]
+fn alloc_cstr_array_is_null_terminated() {
+    let b = Bump::new();
+    let argv = b.alloc_cstr_array(vec!["cc", "-o", "main", "main.c", ""]);
+    let strs = unsafe { read_all(argv) };
+    assert_eq!(strs, ["cc", "-o", "main", "main.c", ""]);
+}
+
+#[test]
+fn alloc_cstr_array_empty() {
+    let b = Bump::new();
+    let argv = b.alloc_cstr_array(std::iter::empty());
+    assert!(unsafe { (*argv).is_null() });
+}
+
+#[test]
+fn alloc_cstr_array_lives_in_the_arena() {
+    let mut b = Bump::new();
+    let owned: Vec<String> = (0..100).map(|i| format!("arg{}", i)).collect();
+    let argv = b.alloc_cstr_array(owned.iter().map(|s| s.as_str()));
+    drop(owned);
+    let strs = unsafe { read_all(argv) };
+    assert_eq!(strs.len(), 100);
+    assert_eq!(strs[42], "arg42");
+    assert!(b
+        .iter_allocated_chunks()
+        .any(|c| { c.as_ptr_range().contains(&(argv as *const _)) }));
+}
+
+#[test]
+#[should_panic(expected = "C strings must not contain NUL bytes")]
+fn alloc_cstr_array_interior_nul() {
+    let b = Bump::new();
+    b.alloc_cstr_array(vec!["ok", "bad\0"]);
+}
@@ -0,0 +1,92 @@
+use bumpalo::Bump;
+
+fn chunk_count(b: &mut Bump) -> usize {
+    b.iter_allocated_chunks_raw().count()
+}
+
+#[test]
+fn child_uses_parent_free_space() {
+    let mut bump = Bump::with_capacity(1024);
+    bump.alloc(1u64);
+    let before = bump.allocated_bytes();
+    let chunks = chunk_count(&mut bump);
+
+    {
+        let child = bump.fork();
+        for i in 0..16u64 {
+            assert_eq!(*child.alloc(i), i);
+        }
+        // Nothing was allocated from the global allocator.
+        assert_eq!(child.iter_allocated_chunks_raw().count(), 1);
+        assert_eq!(child.allocated_objects_count(), 16);
+    }
+
+    assert_eq!(bump.allocated_bytes(), before);
+    assert_eq!(chunk_count(&mut bump), chunks);
+    assert_eq!(bump.allocated_objects_count(), 1);
+}
+
+#[test]
+fn child_grows_independently() {
+    let mut bump = Bump::new();
+    let kept = bump.alloc_str("kept");
+    let before = bump.allocated_bytes();
+
+    {
+        let child = bump.fork();
+        for i in 0..100_000u32 {
+            child.alloc(i);
+        }
+        assert!(child.iter_allocated_chunks_raw().count() > 1);
+        assert_eq!(kept, "kept");
+    }
+
+    assert_eq!(kept, "kept");
+    assert_eq!(bump.allocated_bytes(), before);
+    assert_eq!(chunk_count(&mut bump), 1);
+}
+
+#[test]
+fn parent_allocations_during_fork_are_kept() {
+    let bump = Bump::new();
+    let child = bump.fork();
+    let from_child = child.alloc(1u32);
+    let from_parent = bump.alloc(2u32);
+    assert_eq!((*from_child, *from_parent), (1, 2));
+    drop(child);
+
+    // The parent's allocation survives the child, and the parent keeps
+    // allocating normally.
+    assert_eq!(*from_parent, 2);
+    let more = bump.alloc_slice_fill_copy(100, 3u8);
+    assert!(more.iter().all(|&x| x == 3));
+    assert_eq!(*from_parent, 2);
+}
+
+#[test]
+fn nested_forks() {
+    let bump = Bump::new();
+    let before = bump.allocated_bytes();
+    {
+        let outer = bump.fork();
+        let a = outer.alloc(1u8);
+        {
+            let inner = outer.fork();
+            inner.alloc_slice_fill_copy(10_000, 0u8);
+        }
+        assert_eq!(*a, 1);
+        assert!(outer.allocated_bytes() >= 1);
+    }
+    assert_eq!(bump.allocated_bytes(), before);
+}
+
+#[test]
+fn fork_of_a_full_chunk() {
+    let bump = Bump::new();
+    let capacity = bump.chunk_capacity();
+    bump.alloc_slice_fill_copy(capacity, 0u8);
+
+    let child = bump.fork();
+    assert_eq!(*child.alloc(7u64), 7);
+    assert!(std::ptr::eq(child.parent(), &bump));
+}
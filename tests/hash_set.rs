@@ -0,0 +1,195 @@
+#![cfg(feature = "collections")]
+use bumpalo::{collections::HashSet, Bump};
+use std::collections::hash_map::RandomState;
+
+#[test]
+fn insert_contains_remove() {
+    let b = Bump::new();
+    let mut set: HashSet<u32, RandomState> = HashSet::new_in(&b);
+
+    for i in 0..1000 {
+        assert!(set.insert(i));
+    }
+    for i in 0..1000 {
+        assert!(!set.insert(i));
+    }
+    assert_eq!(set.len(), 1000);
+
+    for i in (0..1000).step_by(2) {
+        assert!(set.remove(&i));
+    }
+    assert_eq!(set.len(), 500);
+
+    for i in 0..1000 {
+        assert_eq!(set.contains(&i), i % 2 == 1);
+    }
+    assert!(!set.contains(&1000));
+}
+
+#[test]
+fn iter_yields_every_element_once() {
+    let b = Bump::new();
+    let mut set: HashSet<u32, RandomState> = HashSet::new_in(&b);
+    set.extend(0..100);
+    set.remove(&50);
+
+    let iter = set.iter();
+    assert_eq!(iter.len(), 99);
+    let mut items: Vec<u32> = iter.cloned().collect();
+    items.sort_unstable();
+    let expected: Vec<u32> = (0..100).filter(|&i| i != 50).collect();
+    assert_eq!(items, expected);
+}
+
+#[test]
+fn borrowed_lookups() {
+    let b = Bump::new();
+    let mut set: HashSet<String, RandomState> = HashSet::new_in(&b);
+    set.insert("foo".to_string());
+    set.insert("bar".to_string());
+
+    assert!(set.contains("foo"));
+    assert_eq!(set.get("bar").map(|s| s.as_str()), Some("bar"));
+    assert_eq!(set.take("foo"), Some("foo".to_string()));
+    assert_eq!(set.take("foo"), None);
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn with_capacity_does_not_grow() {
+    let b = Bump::new();
+    let mut set: HashSet<u64, RandomState> = HashSet::with_capacity_in(100, &b);
+    let capacity = set.capacity();
+    assert!(capacity >= 100);
+
+    let before = b.allocated_bytes();
+    for i in 0..capacity as u64 {
+        set.insert(i);
+    }
+    assert_eq!(b.allocated_bytes(), before);
+    assert_eq!(set.capacity(), capacity);
+}
+
+#[test]
+fn removing_and_reinserting_reuses_the_table() {
+    let b = Bump::new();
+    let mut set: HashSet<u32, RandomState> = HashSet::with_capacity_in(16, &b);
+    let capacity = set.capacity();
+
+    for round in 0..100 {
+        for i in 0..8 {
+            set.insert(round * 8 + i);
+        }
+        for i in 0..8 {
+            assert!(set.remove(&(round * 8 + i)));
+        }
+    }
+    assert!(set.is_empty());
+    assert_eq!(set.capacity(), capacity);
+}
+
+#[test]
+fn clear_keeps_capacity() {
+    let b = Bump::new();
+    let mut set: HashSet<u32, RandomState> = HashSet::new_in(&b);
+    set.extend(0..50);
+    let capacity = set.capacity();
+
+    set.clear();
+    assert!(set.is_empty());
+    assert!(!set.contains(&0));
+    assert_eq!(set.capacity(), capacity);
+    assert_eq!(set.iter().count(), 0);
+}
+
+#[test]
+fn drop_drops_elements() {
+    use std::cell::Cell;
+    use std::hash::{Hash, Hasher};
+
+    struct CountDrops<'a> {
+        key: u32,
+        drops: &'a Cell<usize>,
+    }
+
+    impl PartialEq for CountDrops<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.key == other.key
+        }
+    }
+
+    impl Eq for CountDrops<'_> {}
+
+    impl Hash for CountDrops<'_> {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.key.hash(state);
+        }
+    }
+
+    impl Drop for CountDrops<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    let b = Bump::new();
+    let mut set: HashSet<_, RandomState> = HashSet::new_in(&b);
+
+    // Enough elements to grow the table several times, which moves them
+    // without dropping them.
+    for key in 0..100 {
+        assert!(set.insert(CountDrops { key, drops: &drops }));
+    }
+    assert_eq!(drops.get(), 0);
+
+    // A rejected duplicate and a removed element are dropped right away.
+    assert!(!set.insert(CountDrops {
+        key: 0,
+        drops: &drops
+    }));
+    assert!(set.remove(&CountDrops {
+        key: 1,
+        drops: &drops
+    }));
+    // The rejected duplicate, the value passed to `remove`, and the removed
+    // element.
+    assert_eq!(drops.get(), 3);
+
+    drop(set);
+    assert_eq!(drops.get(), 3 + 99);
+}
+
+#[test]
+fn insert_hashes_once() {
+    use std::cell::Cell;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::BuildHasher;
+
+    struct CountHashes<'a>(&'a Cell<usize>);
+
+    impl BuildHasher for CountHashes<'_> {
+        type Hasher = DefaultHasher;
+        fn build_hasher(&self) -> DefaultHasher {
+            self.0.set(self.0.get() + 1);
+            DefaultHasher::new()
+        }
+    }
+
+    let hashes = Cell::new(0);
+    let b = Bump::new();
+    let mut set = HashSet::with_capacity_and_hasher_in(100, CountHashes(&hashes), &b);
+
+    for i in 0..100u32 {
+        assert!(set.insert(i));
+    }
+    assert!(!set.insert(7));
+    assert_eq!(hashes.get(), 101);
+
+    // A removed element leaves a deleted slot behind, which inserting it
+    // again reuses.
+    let slot = set.get(&42).unwrap() as *const u32;
+    assert!(set.remove(&42));
+    assert!(set.insert(42));
+    assert_eq!(set.get(&42).unwrap() as *const u32, slot);
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "collections")]
+use bumpalo::{collections::Interner, Bump};
+
+#[test]
+fn interning_deduplicates_allocations() {
+    let b = Bump::new();
+    let mut interner = Interner::new_in(&b);
+
+    let words = ["foo", "bar", "foo", "baz", "bar", "foo"];
+    let interned: Vec<&str> = words.iter().map(|w| interner.intern(w)).collect();
+
+    assert_eq!(interned, words);
+    assert_eq!(interner.len(), 3);
+    assert!(std::ptr::eq(interned[0], interned[2]));
+    assert!(std::ptr::eq(interned[0], interned[5]));
+    assert!(std::ptr::eq(interned[1], interned[4]));
+    assert!(!std::ptr::eq(interned[0], interned[1]));
+
+    let before = b.allocated_bytes();
+    interner.intern("baz");
+    assert_eq!(b.allocated_bytes(), before);
+}
+
+#[test]
+fn interned_strings_outlive_interner() {
+    let b = Bump::new();
+    let s = {
+        let mut interner = Interner::new_in(&b);
+        interner.intern("hello")
+    };
+    assert_eq!(s, "hello");
+}
+
+#[test]
+fn interning_empty_string() {
+    let b = Bump::new();
+    let mut interner = Interner::new_in(&b);
+    assert_eq!(interner.intern(""), "");
+    assert_eq!(interner.intern(""), "");
+    assert_eq!(interner.len(), 1);
+}
@@ -0,0 +1,91 @@
+use bumpalo::{Bump, ChunkPool};
+
+fn sum_in(bump: &Bump, n: u64) -> u64 {
+    let xs = bump.alloc_slice_fill_with(n as usize, |i| i as u64);
+    xs.iter().sum()
+}
+
+#[test]
+fn arenas_are_reused() {
+    let pool = ChunkPool::new();
+    assert_eq!(pool.idle(), 0);
+
+    let first_chunk = {
+        let bump = pool.get();
+        assert_eq!(sum_in(&bump, 10), 45);
+        bump.iter_allocated_chunks_raw().next().unwrap().base
+    };
+    assert_eq!(pool.idle(), 1);
+
+    let bump = pool.get();
+    assert_eq!(pool.idle(), 0);
+    assert_eq!(bump.allocated_bytes(), 0);
+    assert_eq!(
+        bump.iter_allocated_chunks_raw().next().unwrap().base,
+        first_chunk
+    );
+}
+
+#[test]
+fn grown_arenas_keep_their_largest_chunk() {
+    let pool = ChunkPool::with_chunk_capacity(64);
+    let capacity = {
+        let bump = pool.get();
+        for i in 0..10_000u32 {
+            bump.alloc(i);
+        }
+        bump.iter_allocated_chunks_raw()
+            .next()
+            .unwrap()
+            .layout
+            .size()
+    };
+
+    let bump = pool.get();
+    let chunks: Vec<_> = bump.iter_allocated_chunks_raw().collect();
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(chunks[0].layout.size(), capacity);
+}
+
+#[test]
+fn concurrent_guards_use_distinct_arenas() {
+    let mut pool = ChunkPool::new();
+    {
+        let a = pool.get();
+        let b = pool.get();
+        let x = a.alloc(1u32);
+        let y = b.alloc(2u32);
+        assert_eq!((*x, *y), (1, 2));
+        assert_eq!(a.allocated_objects_count(), 1);
+        assert_eq!(b.allocated_objects_count(), 1);
+    }
+    assert_eq!(pool.idle(), 2);
+
+    pool.clear();
+    assert_eq!(pool.idle(), 0);
+}
+
+#[test]
+fn guard_can_reset_early() {
+    let pool = ChunkPool::new();
+    let mut bump = pool.get();
+    bump.alloc([0u8; 32]);
+    bump.reset();
+    assert_eq!(bump.allocated_bytes(), 0);
+    assert!(pool.try_get().is_ok());
+}
+
+#[test]
+fn settings_do_not_carry_over_to_the_next_user() {
+    let pool = ChunkPool::new();
+    {
+        let mut bump = pool.get();
+        bump.set_large_slice_alignment(64, 1);
+    }
+
+    let bump = pool.get();
+    let x = bump.alloc(1u8) as *const u8 as usize;
+    let bytes = bump.alloc_slice_copy(&[1u8, 2, 3]);
+    // Without over-alignment, the slice sits right below the byte.
+    assert_eq!(bytes.as_ptr() as usize + 3, x);
+}
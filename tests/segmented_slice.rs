@@ -0,0 +1,73 @@
+use bumpalo::Bump;
+
+fn segments_in_arena<T>(b: &Bump, segments: &[&[T]]) -> bool {
+    let chunks: Vec<_> = b
+        .iter_allocated_chunks_raw()
+        .map(|c| c.base as usize..c.base as usize + c.layout.size())
+        .collect();
+    segments.iter().all(|s| {
+        let start = s.as_ptr() as usize;
+        let end = start + std::mem::size_of_val(*s);
+        chunks.iter().any(|c| c.start <= start && end <= c.end)
+    })
+}
+
+#[test]
+fn alloc_slice_copy_chunked_spans_chunks() {
+    let b = Bump::with_capacity(1024);
+    b.alloc(1u8);
+    let first_chunk = b.chunk_capacity();
+
+    let src: Vec<u64> = (0..1_000_000).collect();
+    let copy = b.alloc_slice_copy_chunked(&src);
+    assert_eq!(copy.len(), src.len());
+    assert!(copy.iter().eq(src.iter()));
+    assert_eq!(copy.iter().len(), src.len());
+    assert_eq!(copy.iter().copied().sum::<u64>(), src.iter().sum::<u64>());
+    for &i in &[0, 1, 127, 128, 500_000, 999_999] {
+        assert_eq!(copy.get(i), Some(&src[i]));
+        assert_eq!(copy[i], src[i]);
+    }
+    assert_eq!(copy.get(src.len()), None);
+
+    // The first segment fills the rest of the first chunk, and the chunks
+    // after it grow geometrically, so there are only a few segments.
+    let lens: Vec<_> = copy.segments().iter().map(|s| s.len()).collect();
+    assert!(lens[0] * 8 <= first_chunk);
+    assert!(lens.len() > 2);
+    assert!(lens.len() < 20);
+    assert_eq!(lens.len(), b.iter_allocated_chunks_raw().count());
+    assert!(lens.windows(2).skip(1).all(|w| w[0] < w[1]));
+    assert!(segments_in_arena(&b, copy.segments()));
+}
+
+#[test]
+fn alloc_slice_copy_chunked_fits_in_current_chunk() {
+    let b = Bump::new();
+    let copy = b.alloc_slice_copy_chunked(&[1u16, 2, 3]);
+    assert_eq!(copy.segments(), [&[1u16, 2, 3][..]]);
+    assert_eq!(format!("{:?}", copy), "[1, 2, 3]");
+}
+
+#[test]
+fn alloc_slice_copy_chunked_empty_and_zero_sized() {
+    let b = Bump::new();
+
+    let empty = b.alloc_slice_copy_chunked::<u32>(&[]);
+    assert!(empty.is_empty());
+    assert_eq!(empty.iter().next(), None);
+    assert!(empty.segments().is_empty());
+
+    let units = b.alloc_slice_copy_chunked(&[(); 1000]);
+    assert_eq!(units.len(), 1000);
+    assert_eq!(units.iter().count(), 1000);
+    assert_eq!(units.segments().len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+fn alloc_slice_copy_chunked_index_out_of_bounds() {
+    let b = Bump::new();
+    let copy = b.alloc_slice_copy_chunked(&[1, 2, 3]);
+    let _ = copy[3];
+}
@@ -17,3 +17,98 @@ fn trailing_comma_in_format_macro() {
     let v = format![in &b, "{}{}", 1, 2, ];
     assert_eq!(v, "12");
 }
+
+#[test]
+fn str_methods_via_deref() {
+    let b = Bump::new();
+    let mut s = String::from_str_in("Hello, World", &b);
+
+    assert!(s.starts_with("Hello"));
+    assert_eq!(s.find(','), Some(5));
+    assert_eq!(s.split(", ").collect::<Vec<_>>(), ["Hello", "World"]);
+    assert_eq!(&s[7..], "World");
+    assert_eq!(s.chars().last(), Some('d'));
+
+    s.make_ascii_uppercase();
+    assert_eq!(s, "HELLO, WORLD");
+}
+
+#[test]
+fn repeat_in() {
+    let bump = Bump::new();
+
+    assert_eq!(String::repeat_in("ab", 0, &bump), "");
+    assert_eq!(String::repeat_in("", 5, &bump), "");
+    assert_eq!(String::repeat_in("é-", 7, &bump), "é-".repeat(7));
+}
+
+#[test]
+fn split_off_segments_a_growing_buffer() {
+    let bump = Bump::new();
+    let mut buf = String::new_in(&bump);
+    let mut tokens = vec![];
+
+    for chunk in &["héllo wo", "rld ünïcode", " tail"] {
+        buf.push_str(chunk);
+        // Split off everything after the last complete token.
+        if let Some(i) = buf.rfind(' ') {
+            let rest = buf.split_off(i + 1);
+            tokens.extend(buf.split_whitespace().map(|t| t.to_owned()));
+            buf = rest;
+        }
+    }
+
+    assert_eq!(tokens, ["héllo", "world", "ünïcode"]);
+    assert_eq!(buf, "tail");
+
+    // The suffix is a separate arena allocation, independent of the prefix.
+    let mut s = String::from_str_in("abcdef", &bump);
+    let mut t = s.split_off(3);
+    t.push('!');
+    s.push('?');
+    assert_eq!(s, "abc?");
+    assert_eq!(t, "def!");
+}
+
+#[test]
+#[should_panic]
+fn split_off_not_on_char_boundary() {
+    let bump = Bump::new();
+    let mut s = String::from_str_in("é", &bump);
+    s.split_off(1);
+}
+
+#[test]
+fn retain_multibyte_in_place() {
+    let bump = Bump::new();
+    let mut s = String::from_str_in("a😀bé😀cñ😀", &bump);
+    let ptr = s.as_ptr();
+    let capacity = s.capacity();
+
+    s.retain(|c| c != '😀');
+    assert_eq!(s, "abécñ");
+    s.retain(|c| !c.is_ascii());
+    assert_eq!(s, "éñ");
+    s.retain(|_| false);
+    assert_eq!(s, "");
+
+    assert_eq!(s.as_ptr(), ptr);
+    assert_eq!(s.capacity(), capacity);
+}
+
+#[test]
+fn retain_panic_leaves_valid_utf8() {
+    let bump = Bump::new();
+    let mut s = String::from_str_in("ééa😀b", &bump);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        s.retain(|c| match c {
+            'é' => false,
+            '😀' => panic!("predicate panicked"),
+            _ => true,
+        });
+    }));
+    assert!(result.is_err());
+    // Only the characters that were kept before the panic remain.
+    assert_eq!(s, "a");
+    assert!(std::str::from_utf8(s.as_bytes()).is_ok());
+}
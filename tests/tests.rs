@@ -112,6 +112,74 @@ fn alloc_slice_copy() {
     assert_eq!(src, dst);
 }
 
+#[test]
+fn alloc_slice_copy_or_reuse() {
+    let b = Bump::new();
+    let used = |b: &Bump| b.chunk_usage().map(|(_, used)| used).sum::<usize>();
+
+    // The most recent allocation is reused, and so is a prefix of it.
+    let x: &[u64] = b.alloc_slice_copy(&[1, 2, 3, 4]);
+    let before = used(&b);
+    unsafe {
+        assert_eq!(b.alloc_slice_copy_or_reuse(x).as_ptr(), x.as_ptr());
+        assert_eq!(b.alloc_slice_copy_or_reuse(&x[..2]).as_ptr(), x.as_ptr());
+    }
+    assert_eq!(used(&b), before);
+
+    // Any other part of it is copied.
+    let y = unsafe { b.alloc_slice_copy_or_reuse(&x[1..3]) };
+    assert_ne!(y.as_ptr(), x[1..].as_ptr());
+    assert_eq!(y, [2, 3]);
+    assert_eq!(used(&b), before + 16);
+
+    // So is an older allocation, even in the same chunk.
+    let y = unsafe { b.alloc_slice_copy_or_reuse(x) };
+    assert_ne!(y.as_ptr(), x.as_ptr());
+    assert_eq!(y, x);
+    assert_eq!(used(&b), before + 48);
+
+    // And slices from outside the arena, or from another arena.
+    let outside = [1u64, 2, 3, 4];
+    let y = unsafe { b.alloc_slice_copy_or_reuse(&outside) };
+    assert_ne!(y.as_ptr(), outside.as_ptr());
+    assert_eq!(y, outside);
+
+    let other = Bump::new();
+    let z: &[u64] = other.alloc_slice_copy(&[7, 8]);
+    assert_ne!(
+        unsafe { b.alloc_slice_copy_or_reuse(z) }.as_ptr(),
+        z.as_ptr()
+    );
+
+    // The result may outlive the slice it was copied from.
+    let copy = {
+        let temporary = vec![9u8; 3];
+        unsafe { b.alloc_slice_copy_or_reuse(&temporary) }
+    };
+    assert_eq!(copy, [9, 9, 9]);
+
+    let empty: &[u64] = &[];
+    assert!(unsafe { b.alloc_slice_copy_or_reuse(empty) }.is_empty());
+}
+
+#[test]
+fn alloc_str_is_a_mutable_copy() {
+    let b = Bump::new();
+    let src = String::from("héllo wörld");
+    let s: &mut str = b.alloc_str(&src);
+    assert_ne!(s.as_ptr(), src.as_ptr());
+
+    s.make_ascii_uppercase();
+    assert_eq!(s, "HéLLO WöRLD");
+    assert_eq!(src, "héllo wörld");
+
+    // Byte-level edits through the `&mut str` API stay valid UTF-8.
+    if let Some(first) = s.get_mut(0..1) {
+        first.make_ascii_lowercase();
+    }
+    assert_eq!(s, "héLLO WöRLD");
+}
+
 #[test]
 fn alloc_slice_clone() {
     let b = Bump::new();
@@ -193,3 +261,800 @@ fn test_alignment() {
         }
     }
 }
+
+#[test]
+fn alloc_layout_repr_c_matches_repr_c_struct() {
+    #[repr(C)]
+    struct Record {
+        a: u8,
+        b: u64,
+        c: u16,
+        d: [u8; 3],
+    }
+
+    let b = Bump::new();
+    let (ptr, offsets) = b.alloc_layout_repr_c(&[
+        Layout::new::<u8>(),
+        Layout::new::<u64>(),
+        Layout::new::<u16>(),
+        Layout::new::<[u8; 3]>(),
+    ]);
+
+    let record = Record {
+        a: 0,
+        b: 0,
+        c: 0,
+        d: [0; 3],
+    };
+    let base = &record as *const Record as usize;
+    assert_eq!(
+        offsets,
+        &[
+            &record.a as *const _ as usize - base,
+            &record.b as *const _ as usize - base,
+            &record.c as *const _ as usize - base,
+            &record.d as *const _ as usize - base,
+        ]
+    );
+    assert_eq!(ptr.as_ptr() as usize % mem::align_of::<Record>(), 0);
+}
+
+#[test]
+fn alloc_layout_repr_c_no_fields() {
+    let b = Bump::new();
+    let (_, offsets) = b.alloc_layout_repr_c(&[]);
+    assert!(offsets.is_empty());
+}
+
+#[test]
+fn reset_single_chunk_keeps_chunk() {
+    let mut b = Bump::new();
+
+    // Resetting an arena that hasn't been allocated into is a no-op.
+    let chunk_start = b.iter_allocated_chunks().next().unwrap().as_ptr() as usize;
+    b.reset();
+    b.reset();
+    assert_eq!(b.allocated_bytes(), 0);
+    assert_eq!(b.iter_allocated_chunks().count(), 1);
+
+    // Allocating and resetting within a single chunk reuses that chunk.
+    let first = b.alloc(1u64) as *const u64 as usize;
+    b.reset();
+    assert_eq!(b.allocated_bytes(), 0);
+    assert_eq!(
+        b.iter_allocated_chunks().next().unwrap().as_ptr() as usize,
+        chunk_start
+    );
+    assert_eq!(b.alloc(2u64) as *const u64 as usize, first);
+
+    // Zero-sized allocations don't move the bump pointer, but they still
+    // count, so the arena isn't clean until it is reset.
+    b.reset();
+    b.alloc(());
+    assert_eq!(b.allocated_objects_count(), 1);
+    assert_eq!(b.reset_returning_used(), 0);
+    assert_eq!(b.allocated_objects_count(), 0);
+    b.assert_reset_clean();
+}
+
+#[test]
+fn alloc_slice_move() {
+    let b = Bump::new();
+
+    let dst = b.alloc_slice_move([vec![1], vec![2, 3], vec![]]);
+    assert_eq!(dst, &[vec![1], vec![2, 3], vec![]]);
+
+    let empty: &mut [String] = b.alloc_slice_move([]);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn arena_writer_truncates_on_char_boundary() {
+    use bumpalo::ArenaWriter;
+    use std::fmt::Write;
+
+    let b = Bump::new();
+
+    let mut w = ArenaWriter::with_capacity_in(5, &b);
+    assert!(w.write_str("ab").is_ok());
+    // "é" is two bytes, so only two of the three fit.
+    assert!(w.write_str("éé").is_err());
+    assert!(w.is_truncated());
+    assert_eq!(w.as_str(), "abé");
+    assert_eq!(w.remaining(), 1);
+
+    // Once truncated, even writes that would fit are rejected.
+    assert!(w.write_str("c").is_err());
+    assert_eq!(w.finish(), ("abé", true));
+
+    let w = ArenaWriter::with_capacity_in(0, &b);
+    assert_eq!(w.finish(), ("", false));
+}
+
+#[test]
+fn arena_writer_allocates_once() {
+    use bumpalo::ArenaWriter;
+    use std::fmt::Write;
+
+    let b = Bump::new();
+
+    let mut w = ArenaWriter::with_capacity_in(64, &b);
+    let after_reserve = b.alloc(0u8) as *const u8 as usize;
+    for i in 0..10 {
+        write!(w, "{},", i).unwrap();
+    }
+    let after_write = b.alloc(0u8) as *const u8 as usize;
+
+    assert_eq!(after_reserve - after_write, 1);
+    assert_eq!(w.finish(), ("0,1,2,3,4,5,6,7,8,9,", false));
+}
+
+#[test]
+fn alloc_with_align() {
+    let b = Bump::new();
+
+    for &align in &[1, 2, 8, 64, 256, 4096] {
+        b.alloc(0u8);
+        let x = b.alloc_with_align(0xABCD_u16, align);
+        assert_eq!(*x, 0xABCD);
+        assert_eq!(x as *mut u16 as usize % align.max(2), 0);
+    }
+
+    // Smaller alignments than the type's own are ignored.
+    let x = b.alloc_with_align(1u64, 1);
+    assert_eq!(x as *mut u64 as usize % mem::align_of::<u64>(), 0);
+
+    assert!(b.try_alloc_with_align(1u8, 0).is_err());
+    assert!(b.try_alloc_with_align(1u8, 48).is_err());
+}
+
+#[test]
+#[should_panic(expected = "alignment must be a power of two")]
+fn alloc_with_align_rejects_non_power_of_two() {
+    Bump::new().alloc_with_align(1u8, 3);
+}
+
+#[test]
+fn alloc_aligned_slice() {
+    let b = Bump::new();
+    for len in 0..20 {
+        b.alloc(1u8);
+        let mut xs = b.alloc_aligned_slice::<u16, 64>(len);
+        assert_eq!(xs.as_ptr() as usize % 64, 0);
+        assert_eq!(xs.len(), len);
+        assert!(xs.iter().all(|&x| x == 0));
+        for (i, x) in xs.as_mut_slice().iter_mut().enumerate() {
+            *x = i as u16;
+        }
+        assert!(xs.iter().enumerate().all(|(i, &x)| x == i as u16));
+    }
+
+    // `T`'s own alignment wins when it is larger.
+    let xs = b.alloc_aligned_slice::<u64, 1>(4);
+    assert_eq!(xs.as_ptr() as usize % std::mem::align_of::<u64>(), 0);
+    assert_eq!(bumpalo::Aligned::<&mut [u64], 1>::ALIGN, 1);
+
+    let xs = b.alloc_aligned_slice::<(), 128>(10).into_inner();
+    assert_eq!(xs.as_ptr() as usize % 128, 0);
+    assert_eq!(xs.len(), 10);
+
+    assert!(b.try_alloc_aligned_slice::<u8, 6>(1).is_err());
+}
+
+#[test]
+#[should_panic(expected = "alignment must be a power of two")]
+fn alloc_aligned_slice_rejects_non_power_of_two() {
+    Bump::new().alloc_aligned_slice::<u8, 0>(1);
+}
+
+#[test]
+fn allocated_objects_count() {
+    let mut b = Bump::new();
+    assert_eq!(b.allocated_objects_count(), 0);
+
+    b.alloc(());
+    b.alloc_layout(Layout::new::<u64>());
+    assert_eq!(b.allocated_objects_count(), 2);
+
+    // Failed allocations don't count.
+    assert!(b.try_alloc_with_align(0u8, 3).is_err());
+    assert_eq!(b.allocated_objects_count(), 2);
+
+    // Allocations that need a new chunk count exactly once, too.
+    for _ in 0..100 {
+        b.alloc([0u8; 1024]);
+    }
+    assert!(b.iter_allocated_chunks().count() > 1);
+    assert_eq!(b.allocated_objects_count(), 102);
+
+    b.reset();
+    assert_eq!(b.allocated_objects_count(), 0);
+    b.alloc(1u32);
+    assert_eq!(b.allocated_objects_count(), 1);
+}
+
+#[test]
+fn with_chunk_alignment() {
+    for &align in &[1, 16, 64, 4096, 1 << 16] {
+        let b = Bump::with_chunk_alignment(align);
+        let mut used = 0;
+        for _ in 0..4 {
+            // Fill the rest of the current chunk, so that the allocation
+            // ends at the very beginning of the chunk, and the next one
+            // needs a fresh chunk.
+            let rest = b.chunk_capacity() - used;
+            let p = b.alloc_slice_fill_copy(rest, 0u8).as_ptr() as usize;
+            assert_eq!(p % align, 0);
+            b.alloc(0u8);
+            used = 1;
+        }
+    }
+
+    assert!(Bump::try_with_chunk_alignment(4096).is_ok());
+    assert!(Bump::try_with_chunk_alignment(0).is_err());
+    assert!(Bump::try_with_chunk_alignment(3000).is_err());
+}
+
+#[test]
+#[should_panic(expected = "alignment must be a power of two")]
+fn with_chunk_alignment_rejects_non_power_of_two() {
+    Bump::with_chunk_alignment(24);
+}
+
+#[test]
+fn with_capacity_page_rounded() {
+    for &(capacity, rounded) in &[
+        (0, 4096),
+        (1, 4096),
+        (4096, 4096),
+        (4097, 8192),
+        (100_000, 102_400),
+    ] {
+        let mut b = Bump::with_capacity_page_rounded(capacity);
+        assert_eq!(b.chunk_capacity(), rounded);
+
+        // Filling the chunk exactly takes neither more nor less room than
+        // its capacity.
+        for _ in 0..rounded / 64 {
+            b.alloc([0u8; 64]);
+        }
+        assert_eq!(b.allocated_bytes(), rounded);
+        assert_eq!(b.iter_allocated_chunks().count(), 1);
+
+        // Resetting keeps the exactly-sized chunk.
+        b.reset();
+        assert_eq!(b.chunk_capacity(), rounded);
+    }
+
+    assert!(Bump::try_with_capacity_page_rounded(usize::MAX).is_err());
+    assert!(Bump::try_with_capacity_page_rounded(isize::MAX as usize).is_err());
+}
+
+#[test]
+fn large_slice_alignment() {
+    let mut b = Bump::new();
+    b.set_large_slice_alignment(64, 128);
+
+    for len in 120..140 {
+        // Knock the bump pointer off of any nice alignment first.
+        b.alloc(0u8);
+
+        let copy = b.alloc_slice_copy(&vec![7u8; len]);
+        assert_eq!(copy, &vec![7u8; len][..]);
+        if len >= 128 {
+            assert_eq!(copy.as_ptr() as usize % 64, 0);
+        }
+
+        b.alloc(0u8);
+        let fill = b.alloc_slice_fill_copy(len, 9u8);
+        assert!(fill.iter().all(|&x| x == 9));
+        if len >= 128 {
+            assert_eq!(fill.as_ptr() as usize % 64, 0);
+        }
+    }
+
+    // Small slices are left alone.
+    b.alloc(0u8);
+    let before = b.alloc(0u8) as *const u8 as usize;
+    let small = b.alloc_slice_copy(&[1u8, 2, 3]);
+    assert_eq!(small.as_ptr() as usize, before - 3);
+}
+
+#[test]
+#[should_panic(expected = "alignment must be a power of two")]
+fn large_slice_alignment_rejects_non_power_of_two() {
+    Bump::new().set_large_slice_alignment(48, 16);
+}
+
+#[test]
+fn iter_allocated_chunks_raw() {
+    let mut b = Bump::with_chunk_alignment(4096);
+    for i in 0..10_000u64 {
+        b.alloc(i);
+    }
+
+    let raw: Vec<_> = b.iter_allocated_chunks_raw().collect();
+    assert!(raw.len() > 1);
+    for chunk in &raw {
+        assert_eq!(chunk.layout.align(), 4096);
+        assert_eq!(chunk.base as usize % 4096, 0);
+        assert!(chunk.used_start as usize >= chunk.base as usize);
+        assert!(
+            chunk.used_start as usize + chunk.used_len <= chunk.base as usize + chunk.layout.size()
+        );
+    }
+
+    let used: Vec<_> = raw.iter().map(|c| (c.used_start, c.used_len)).collect();
+    let expected: Vec<_> = b
+        .iter_allocated_chunks()
+        .map(|c| (c.as_ptr() as *mut u8, c.len()))
+        .collect();
+    assert_eq!(used, expected);
+}
+
+#[test]
+fn alloc_slice_copy_uninit() {
+    use std::mem::MaybeUninit;
+
+    let b = Bump::new();
+    let mut src = [MaybeUninit::<u64>::uninit(); 8];
+    for (i, x) in src.iter_mut().enumerate().step_by(2) {
+        *x = MaybeUninit::new(i as u64 * 10);
+    }
+
+    let copy = b.alloc_slice_copy_uninit(&src);
+    assert_eq!(copy.len(), 8);
+    assert_ne!(copy.as_ptr(), src.as_ptr());
+    for i in (0..8).step_by(2) {
+        assert_eq!(unsafe { copy[i].assume_init() }, i as u64 * 10);
+    }
+}
+
+#[test]
+fn reset_returning_used() {
+    let mut b = Bump::new();
+    assert_eq!(b.reset_returning_used(), 0);
+
+    b.alloc(1u64);
+    assert_eq!(b.reset_returning_used(), 8);
+
+    for i in 0..10_000u32 {
+        b.alloc(i);
+    }
+    assert!(b.iter_allocated_chunks().count() > 1);
+    let used = b.allocated_bytes();
+    assert_eq!(b.reset_returning_used(), used);
+    assert_eq!(b.allocated_bytes(), 0);
+    assert_eq!(b.iter_allocated_chunks().count(), 1);
+}
+
+#[test]
+fn alloc_layout_with() {
+    let b = Bump::new();
+    let layout = Layout::from_size_align(24, 8).unwrap();
+
+    let mut called = false;
+    let p = unsafe {
+        b.alloc_layout_with(layout, |p| {
+            called = true;
+            assert_eq!(p as usize % 8, 0);
+            std::ptr::write_bytes(p, 0xab, 24);
+        })
+    };
+    assert!(called);
+
+    let bytes = unsafe { std::slice::from_raw_parts(p.as_ptr(), 24) };
+    assert!(bytes.iter().all(|&x| x == 0xab));
+    assert_eq!(b.allocated_objects_count(), 1);
+}
+
+#[test]
+fn shrink_last_reclaims_last_allocation() {
+    let b = Bump::new();
+    let layout = Layout::array::<u32>(64).unwrap();
+    let p = b.alloc_layout(layout);
+    unsafe {
+        let words = p.as_ptr() as *mut u32;
+        for i in 0..64 {
+            words.add(i).write(i as u32);
+        }
+    }
+    assert_eq!(b.allocated_bytes(), 256);
+
+    let old = p;
+    let p = unsafe { b.shrink_last(p, layout, 16) };
+    assert_eq!(b.allocated_bytes(), 16);
+    // The kept bytes moved up to where the block used to end.
+    assert_eq!(p.as_ptr() as usize, old.as_ptr() as usize + 240);
+    assert_eq!(p.as_ptr() as usize % 4, 0);
+    let words = unsafe { std::slice::from_raw_parts(p.as_ptr() as *const u32, 4) };
+    assert_eq!(words, [0, 1, 2, 3]);
+
+    // The reclaimed bytes are reused by the next allocation.
+    let q = b.alloc_layout(Layout::array::<u32>(60).unwrap());
+    assert_eq!(q.as_ptr() as usize + 240, p.as_ptr() as usize);
+    assert_eq!(b.allocated_bytes(), 256);
+}
+
+#[test]
+fn shrink_last_only_reclaims_whole_alignments() {
+    let b = Bump::new();
+    let layout = Layout::from_size_align(64, 16).unwrap();
+    let p = b.alloc_layout(layout);
+    let p = unsafe { b.shrink_last(p, layout, 40) };
+    assert_eq!(p.as_ptr() as usize % 16, 0);
+    assert_eq!(b.allocated_bytes(), 48);
+}
+
+#[test]
+fn shrink_last_not_last_allocation() {
+    let b = Bump::new();
+    let layout = Layout::array::<u8>(32).unwrap();
+    let p = b.alloc_layout(layout);
+    b.alloc(0u8);
+    let used = b.allocated_bytes();
+
+    let q = unsafe { b.shrink_last(p, layout, 8) };
+    assert_eq!(p, q);
+    assert_eq!(b.allocated_bytes(), used);
+}
+
+#[test]
+#[should_panic(expected = "`new_size` must not be greater than the block's size")]
+fn shrink_last_larger_size() {
+    let b = Bump::new();
+    let layout = Layout::array::<u8>(8).unwrap();
+    let p = b.alloc_layout(layout);
+    unsafe {
+        b.shrink_last(p, layout, 9);
+    }
+}
+
+#[test]
+fn chunk_usage() {
+    let b = Bump::new();
+    let usage: Vec<_> = b.chunk_usage().collect();
+    assert_eq!(usage, [(b.chunk_capacity(), 0)]);
+
+    let small = b.chunk_capacity();
+    for i in 0..10_000u32 {
+        b.alloc(i);
+    }
+    // One large allocation forces a chunk of its own, which is mostly used.
+    b.alloc_slice_fill_copy(1 << 20, 0u8);
+
+    let usage: Vec<_> = b.chunk_usage().collect();
+    assert_eq!(usage.len(), b.iter_allocated_chunks_raw().count());
+    assert!(usage.len() > 2);
+    assert_eq!(usage[0].0, b.chunk_capacity());
+    assert!(usage[0].1 >= 1 << 20);
+    assert!(usage.iter().all(|&(capacity, used)| used <= capacity));
+    assert_eq!(usage.last().unwrap().0, small);
+    assert_eq!(
+        usage.iter().map(|&(_, used)| used).sum::<usize>(),
+        b.allocated_bytes()
+    );
+}
+
+#[test]
+fn is_empty() {
+    let mut b = Bump::new();
+    assert!(b.is_empty());
+
+    b.alloc_slice_fill_default::<u64>(0);
+    b.alloc([(); 100]);
+    assert!(b.is_empty());
+
+    for i in 0..10_000u32 {
+        b.alloc(i);
+    }
+    assert!(!b.is_empty());
+
+    // `reset` keeps a chunk around but empties it.
+    b.reset();
+    assert_eq!(b.iter_allocated_chunks_raw().count(), 1);
+    assert!(b.is_empty());
+
+    // Only the chunk skipped over by a large first allocation is empty.
+    let b = Bump::new();
+    b.alloc_slice_fill_copy(b.chunk_capacity() + 1, 0u8);
+    assert_eq!(b.iter_allocated_chunks_raw().count(), 2);
+    assert!(!b.is_empty());
+}
+
+#[test]
+fn assert_reset_clean() {
+    let mut b = Bump::new();
+    b.assert_reset_clean();
+
+    for i in 0..10_000u64 {
+        b.alloc(i);
+    }
+    b.reset();
+    b.assert_reset_clean();
+}
+
+#[test]
+#[should_panic(expected = "arena is not clean: 1 allocations were made since it was reset")]
+fn assert_reset_clean_zero_sized() {
+    let mut b = Bump::new();
+    b.reset();
+    b.alloc(());
+    b.assert_reset_clean();
+}
+
+#[test]
+#[should_panic(expected = "arena is not clean: 4 bytes are allocated in it")]
+fn assert_reset_clean_bytes_in_use() {
+    let mut b = Bump::new();
+    b.reset();
+    b.alloc(1u32);
+    b.assert_reset_clean();
+}
+
+#[test]
+#[should_panic(expected = "arena is not clean: it has 2 chunks")]
+fn assert_reset_clean_extra_chunks() {
+    let b = Bump::new();
+    b.alloc_slice_fill_copy(b.chunk_capacity() + 1, 0u8);
+    b.assert_reset_clean();
+}
+
+#[test]
+fn debug_summary() {
+    let b = Bump::new();
+    let cap = b.chunk_capacity();
+    b.alloc(1u64);
+    assert_eq!(
+        format!("{:?}", b),
+        format!(
+            "Bump {{ chunks: 1, capacity: {}, used: 8, remaining: {} }}",
+            cap,
+            cap - 8
+        )
+    );
+
+    b.alloc_slice_fill_copy(cap, 0u8);
+    let (capacity, used): (usize, usize) = b
+        .chunk_usage()
+        .fold((0, 0), |(c, u), (cc, cu)| (c + cc, u + cu));
+    assert_eq!(
+        format!("{:?}", b),
+        format!(
+            "Bump {{ chunks: 2, capacity: {}, used: {}, remaining: {} }}",
+            capacity,
+            used,
+            b.chunk_capacity() - cap
+        )
+    );
+}
+
+#[test]
+fn used_chunks() {
+    let mut b = Bump::new();
+    assert_eq!(b.used_chunks(), 0);
+
+    b.alloc(1u8);
+    assert_eq!(b.used_chunks(), 1);
+
+    for i in 0..10_000u32 {
+        b.alloc(i);
+    }
+    let chunks = b.iter_allocated_chunks_raw().count();
+    assert!(chunks > 1);
+    assert_eq!(b.used_chunks(), chunks);
+
+    b.reset();
+    assert_eq!(b.used_chunks(), 0);
+
+    // A first allocation too big for the initial chunk leaves that chunk
+    // empty behind the new one.
+    let b = Bump::new();
+    let capacity = b.chunk_capacity();
+    b.alloc_slice_fill_copy(capacity + 1, 0u8);
+    assert_eq!(b.iter_allocated_chunks_raw().count(), 2);
+    assert_eq!(b.used_chunks(), 1);
+}
+
+/// An iterator that claims to have `claimed` elements, no matter how many it
+/// actually yields.
+struct LyingLen<I> {
+    iter: I,
+    claimed: usize,
+}
+
+impl<I: Iterator> Iterator for LyingLen<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.claimed, Some(self.claimed))
+    }
+}
+
+impl<I: Iterator> ExactSizeIterator for LyingLen<I> {}
+
+#[test]
+fn alloc_slice_fill_iter_underrun() {
+    let b = Bump::new();
+    let before = b.allocated_bytes();
+
+    let x = b.alloc_slice_fill_iter(LyingLen {
+        iter: 0..3u64,
+        claimed: 10,
+    });
+    assert_eq!(x, [0, 1, 2]);
+    // The unused tail was given back.
+    assert_eq!(b.allocated_bytes() - before, 3 * mem::size_of::<u64>());
+
+    let y = b.alloc(42u64);
+    assert_eq!(*y, 42);
+    assert_eq!(x, [0, 1, 2]);
+
+    let empty = b
+        .try_alloc_slice_fill_iter(LyingLen {
+            iter: std::iter::empty::<u64>(),
+            claimed: 4,
+        })
+        .unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn alloc_slice_fill_iter_overrun() {
+    let b = Bump::new();
+    let x = b.alloc_slice_fill_iter(LyingLen {
+        iter: 0..100u32,
+        claimed: 5,
+    });
+    assert_eq!(x, [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn small_allocations_stay_aligned_and_in_bounds() {
+    #[repr(align(32))]
+    #[derive(Clone, Copy)]
+    struct Align32 {
+        _bytes: [u8; 32],
+    }
+
+    let b = Bump::new();
+    let mut ranges = vec![];
+    for i in 0..2_000 {
+        let (ptr, size, align) = match i % 4 {
+            0 => (b.alloc(1u8) as *mut u8 as usize, 1, 1),
+            1 => (b.alloc(2u16) as *mut u16 as usize, 2, 2),
+            2 => (b.alloc([3u64; 2]) as *mut [u64; 2] as usize, 16, 8),
+            _ => (
+                b.alloc(Align32 { _bytes: [4; 32] }) as *mut Align32 as usize,
+                32,
+                32,
+            ),
+        };
+        assert_eq!(ptr % align, 0);
+        ranges.push((ptr, ptr + size));
+    }
+
+    let chunks: Vec<_> = b.iter_allocated_chunks_raw().collect();
+    for &(start, end) in &ranges {
+        assert!(chunks.iter().any(|c| {
+            let used_start = c.used_start as usize;
+            start >= used_start && end <= used_start + c.used_len
+        }));
+    }
+
+    ranges.sort_unstable();
+    for pair in ranges.windows(2) {
+        assert!(pair[0].1 <= pair[1].0);
+    }
+}
+
+#[test]
+fn alloc_slice_copy_of_references() {
+    fn first_words<'a, 'b>(bump: &'b Bump, lines: &[&'a str]) -> &'b mut [&'a str]
+    where
+        'a: 'b,
+    {
+        let words: Vec<&'a str> = lines
+            .iter()
+            .filter_map(|line| line.split_whitespace().next())
+            .collect();
+        bump.alloc_slice_copy(&words)
+    }
+
+    let bump = Bump::new();
+
+    // The strings are created after the arena and dropped before it; the
+    // arena copy of the references only borrows them.
+    let owned = [String::from("alpha beta"), String::from("gamma delta")];
+    let lines: Vec<&str> = owned.iter().map(|s| s.as_str()).collect();
+
+    let words = first_words(&bump, &lines);
+    assert_eq!(words, ["alpha", "gamma"]);
+    words[1] = "omega";
+    assert_eq!(words, ["alpha", "omega"]);
+
+    let copied: &mut [&str] = bump.try_alloc_slice_copy(&lines).unwrap();
+    assert_eq!(copied, ["alpha beta", "gamma delta"]);
+}
+
+#[test]
+fn zero_sized_allocations_do_not_use_the_arena() {
+    #[repr(align(4096))]
+    struct BigAlignZst;
+
+    let b = Bump::new();
+    let chunk = b.iter_allocated_chunks_raw().next().unwrap();
+
+    // A billion allocations only take a moment when optimized; keep unoptimized
+    // test runs (and valgrind) quick with fewer.
+    let count: u32 = if cfg!(debug_assertions) {
+        10_000_000
+    } else {
+        1_000_000_000
+    };
+    for _ in 0..count {
+        b.alloc(());
+    }
+    for _ in 0..1_000 {
+        let z = b.alloc(BigAlignZst) as *mut BigAlignZst as usize;
+        assert_ne!(z, 0);
+        assert_eq!(z % 4096, 0);
+    }
+    b.alloc_slice_fill_copy(1_000, ());
+
+    assert_eq!(b.allocated_bytes(), 0);
+    let chunks: Vec<_> = b.iter_allocated_chunks_raw().collect();
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(chunks[0].base, chunk.base);
+    assert_eq!(chunks[0].used_len, 0);
+}
+
+#[test]
+fn alloc_slice_copy_raw() {
+    let b = Bump::new();
+    let src = [1u32, 2, 3, 4];
+    let p = b.alloc_slice_copy_raw(&src);
+    assert_eq!(p.as_ptr() as usize % mem::align_of::<u32>(), 0);
+
+    unsafe {
+        for i in 0..src.len() {
+            *p.as_ptr().add(i) *= 10;
+        }
+        assert_eq!(std::slice::from_raw_parts(p.as_ptr(), 4), [10, 20, 30, 40]);
+    }
+    assert_eq!(src, [1, 2, 3, 4]);
+
+    let empty = b.alloc_slice_copy_raw::<u64>(&[]);
+    assert_eq!(empty.as_ptr() as usize % mem::align_of::<u64>(), 0);
+}
+
+#[test]
+fn alloc_bytes() {
+    let b = Bump::new();
+
+    let before = b.allocated_bytes();
+    assert!(b.alloc_bytes(0).is_empty());
+    assert!(b.alloc_zeroed_bytes(0).is_empty());
+    assert_eq!(b.allocated_bytes(), before);
+
+    let buf = b.alloc_bytes(100);
+    assert_eq!(buf.len(), 100);
+    for byte in buf.iter_mut() {
+        *byte = std::mem::MaybeUninit::new(0xff);
+    }
+    assert_eq!(b.allocated_bytes(), before + 100);
+
+    // Zeroed even when the arena's memory was dirty before.
+    let mut b = b;
+    b.reset();
+    let zeroed = b.alloc_zeroed_bytes(100);
+    assert!(zeroed.iter().all(|&byte| byte == 0));
+    zeroed[99] = 1;
+
+    assert!(b.try_alloc_bytes(usize::MAX).is_err());
+    assert!(b.try_alloc_zeroed_bytes(isize::MAX as usize + 1).is_err());
+}
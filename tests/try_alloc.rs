@@ -1,4 +1,4 @@
-use bumpalo::{AllocOrInitError, Bump};
+use bumpalo::{AllocOrInitError, Bump, SliceAllocError};
 use rand::Rng;
 use std::alloc::{GlobalAlloc, Layout, System};
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -209,6 +209,131 @@ fn main() {
                 )
             },
         ),
+        test!(
+            "test try_alloc_slice_copy with and without global allocation failures",
+            || {
+                test_static_size_alloc(
+                    |bump| assert!(bump.try_alloc_slice_copy(&[1u8, 2, 3]).is_ok()),
+                    |bump| {
+                        assert!(matches!(
+                            bump.try_alloc_slice_copy(&[1u8, 2, 3]),
+                            Err(SliceAllocError::OutOfMemory)
+                        ))
+                    },
+                )
+            },
+        ),
+        test!(
+            "test try_alloc_slice_clone with and without global allocation failures",
+            || {
+                test_static_size_alloc(
+                    |bump| assert!(bump.try_alloc_slice_clone(&[1u8, 2, 3]).is_ok()),
+                    |bump| assert!(bump.try_alloc_slice_clone(&[1u8, 2, 3]).is_err()),
+                )
+            },
+        ),
+        test!(
+            "test try_alloc_slice_fill_with with and without global allocation failures",
+            || {
+                test_static_size_alloc(
+                    |bump| assert!(bump.try_alloc_slice_fill_with(3, |i| i as u8).is_ok()),
+                    |bump| {
+                        assert!(bump
+                            .try_alloc_slice_fill_with::<u8, _>(3, |_| unreachable!())
+                            .is_err())
+                    },
+                )
+            },
+        ),
+        test!(
+            "test try_alloc_slice_copy_uninit with and without global allocation failures",
+            || {
+                use std::mem::MaybeUninit;
+
+                test_static_size_alloc(
+                    |bump| {
+                        let src = [MaybeUninit::<u8>::uninit(); 3];
+                        assert!(bump.try_alloc_slice_copy_uninit(&src).is_ok())
+                    },
+                    |bump| {
+                        let src = [MaybeUninit::<u8>::uninit(); 3];
+                        assert!(bump.try_alloc_slice_copy_uninit(&src).is_err())
+                    },
+                )
+            },
+        ),
+        test!(
+            "test try_alloc_slice_copy_raw with and without global allocation failures",
+            || {
+                test_static_size_alloc(
+                    |bump| assert!(bump.try_alloc_slice_copy_raw(&[1u8, 2, 3]).is_ok()),
+                    |bump| assert!(bump.try_alloc_slice_copy_raw(&[1u8, 2, 3]).is_err()),
+                )
+            },
+        ),
+        test!(
+            "test try_alloc_slice_copy_or_reuse with and without global allocation failures",
+            || {
+                test_static_size_alloc(
+                    |bump| {
+                        let copy = unsafe { bump.try_alloc_slice_copy_or_reuse(&[1u8, 2, 3]) };
+                        assert!(copy.is_ok())
+                    },
+                    |bump| {
+                        let copy = unsafe { bump.try_alloc_slice_copy_or_reuse(&[1u8, 2, 3]) };
+                        assert!(copy.is_err())
+                    },
+                )
+            },
+        ),
+        test!(
+            "test try_alloc_slice_copy_chunked with and without global allocation failures",
+            || {
+                test_static_size_alloc(
+                    |bump| assert!(bump.try_alloc_slice_copy_chunked(&[1u8, 2, 3]).is_ok()),
+                    |bump| assert!(bump.try_alloc_slice_copy_chunked(&[1u8, 2, 3]).is_err()),
+                )
+            },
+        ),
+        test!(
+            "test try_alloc_bytes with and without global allocation failures",
+            || {
+                test_static_size_alloc(
+                    |bump| assert_eq!(bump.try_alloc_bytes(16).unwrap().len(), 16),
+                    |bump| assert!(bump.try_alloc_bytes(16).is_err()),
+                )
+            },
+        ),
+        test!(
+            "test try_alloc_zeroed_bytes with and without global allocation failures",
+            || {
+                test_static_size_alloc(
+                    |bump| assert_eq!(bump.try_alloc_zeroed_bytes(16).unwrap(), [0; 16]),
+                    |bump| assert!(bump.try_alloc_zeroed_bytes(16).is_err()),
+                )
+            },
+        ),
+        test!(
+            "test try_alloc_str with and without global allocation failures",
+            || {
+                test_static_size_alloc(
+                    |bump| assert_eq!(bump.try_alloc_str("hello").unwrap(), "hello"),
+                    |bump| assert!(bump.try_alloc_str("hello").is_err()),
+                )
+            },
+        ),
+        test!("test try_alloc_slice_fill_with layout overflow", || {
+            let bump = Bump::try_new().unwrap();
+            assert!(matches!(
+                bump.try_alloc_slice_fill_default::<u64>(usize::MAX),
+                Err(SliceAllocError::LayoutOverflow)
+            ));
+            let len = isize::MAX as usize + 1;
+            assert!(matches!(
+                bump.try_alloc_slice_fill_copy(len, 0u8),
+                Err(SliceAllocError::LayoutOverflow)
+            ));
+        }),
         #[cfg(feature = "collections")]
         test!("test Vec::try_reserve and Vec::try_reserve_exact", || {
             use bumpalo::collections::Vec;
@@ -241,6 +366,31 @@ fn main() {
                 assert!(vec.try_reserve_exact(chunk_cap + 1).is_ok());
             });
         }),
+        #[cfg(feature = "collections")]
+        test!("test Vec::try_push when growing fails", || {
+            use bumpalo::collections::Vec;
+
+            let bump = Bump::try_new().unwrap();
+            let cap = bump.chunk_capacity() / 8;
+            let mut vec = Vec::<u64>::with_capacity_in(cap, &bump);
+            vec.extend(0..cap as u64);
+            let ptr = vec.as_ptr();
+
+            GLOBAL_ALLOCATOR.with_alloc_failures(|| {
+                // The buffer fills the only chunk, so growing it needs a new
+                // chunk, which the global allocator refuses.
+                assert!(vec.try_push(u64::MAX) == Err(u64::MAX));
+                assert!(vec.len() == cap);
+                assert!(vec.capacity() == cap);
+                assert!(vec.as_ptr() == ptr);
+                assert!(vec.iter().copied().eq(0..cap as u64));
+            });
+
+            GLOBAL_ALLOCATOR.with_successful_allocs(|| {
+                assert!(vec.try_push(u64::MAX) == Ok(()));
+                assert!(vec.len() == cap + 1);
+            });
+        }),
     ];
 
     for (name, test) in tests.iter() {
@@ -1,5 +1,8 @@
 #![cfg(feature = "collections")]
-use bumpalo::{collections::Vec, vec, Bump};
+use bumpalo::{
+    collections::{CollectionAllocErr, Vec},
+    vec, Bump,
+};
 use std::cell::Cell;
 
 #[test]
@@ -83,3 +86,810 @@ quickcheck::quickcheck! {
         }
     }
 }
+
+#[test]
+fn test_append_moves_non_copy_elements() {
+    let b = Bump::new();
+    let mut v = bumpalo::vec![in &b; String::from("a"), String::from("b")];
+    let mut w = bumpalo::vec![in &b; String::from("c"), String::from("d")];
+
+    v.append(&mut w);
+
+    assert_eq!(v, ["a", "b", "c", "d"]);
+    assert!(w.is_empty());
+
+    // `w` is still usable after being emptied.
+    w.push(String::from("e"));
+    assert_eq!(w, ["e"]);
+}
+
+#[test]
+fn test_dedup_by_drops_removed_elements() {
+    #[derive(Debug)]
+    struct Dropper<'a> {
+        key: u32,
+        drops: &'a Cell<usize>,
+    }
+
+    impl Drop for Dropper<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    let b = Bump::new();
+    let mut v = Vec::new_in(&b);
+    for &key in &[1, 1, 2, 3, 3, 3, 1] {
+        v.push(Dropper { key, drops: &drops });
+    }
+
+    v.dedup_by(|a, b| a.key == b.key);
+
+    let keys: std::vec::Vec<_> = v.iter().map(|d| d.key).collect();
+    assert_eq!(keys, [1, 2, 3, 1]);
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn test_slice_methods_via_deref() {
+    let b = Bump::new();
+    let mut v = bumpalo::vec![in &b; 3, 1, 2, 5, 4];
+
+    v.sort_unstable();
+    assert_eq!(v, [1, 2, 3, 4, 5]);
+    assert_eq!(v.binary_search(&4), Ok(3));
+    assert_eq!(v.first(), Some(&1));
+    assert_eq!(v.last(), Some(&5));
+    assert!(v.contains(&2));
+
+    let windows: std::vec::Vec<_> = v.windows(2).map(|w| w[1] - w[0]).collect();
+    assert_eq!(windows, [1, 1, 1, 1]);
+    let chunks: std::vec::Vec<_> = v.chunks(2).map(|c| c.len()).collect();
+    assert_eq!(chunks, [2, 2, 1]);
+
+    v.reverse();
+    v[1..4].swap(0, 2);
+    assert_eq!(v, [5, 2, 3, 4, 1]);
+
+    for x in v.iter_mut() {
+        *x *= 10;
+    }
+    assert_eq!(v, [50, 20, 30, 40, 10]);
+}
+
+#[test]
+fn test_with_capacity_in_zero_does_not_allocate() {
+    let bump = Bump::new();
+    let before = bump.alloc(0u8) as *const u8 as usize;
+    let v: Vec<u64> = Vec::with_capacity_in(0, &bump);
+    let after = bump.alloc(0u8) as *const u8 as usize;
+
+    // The bump pointer only moved for the second `u8`.
+    assert_eq!(before - after, 1);
+    assert_eq!(v.capacity(), 0);
+    assert_eq!(v.as_ptr() as usize % std::mem::align_of::<u64>(), 0);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn test_with_capacity_in_overflow_panics() {
+    let bump = Bump::new();
+    let _: Vec<u64> = Vec::with_capacity_in(usize::MAX / 4, &bump);
+}
+
+#[test]
+fn test_try_with_capacity_in() {
+    let bump = Bump::new();
+
+    let mut v: Vec<u32> = Vec::try_with_capacity_in(8, &bump).unwrap();
+    assert!(v.capacity() >= 8);
+    v.extend_from_slice(&[1, 2, 3]);
+    assert_eq!(v, [1, 2, 3]);
+
+    let v: Vec<u32> = Vec::try_with_capacity_in(0, &bump).unwrap();
+    assert_eq!(v.capacity(), 0);
+
+    assert_eq!(
+        Vec::<u64>::try_with_capacity_in(usize::MAX / 4, &bump).unwrap_err(),
+        CollectionAllocErr::CapacityOverflow
+    );
+    assert_eq!(
+        Vec::<u8>::try_with_capacity_in(isize::MAX as usize + 1, &bump).unwrap_err(),
+        CollectionAllocErr::CapacityOverflow
+    );
+}
+
+#[test]
+fn test_shrink_to_fit_reclaims_arena_tail() {
+    let bump = Bump::new();
+    let mut v: Vec<u32> = Vec::with_capacity_in(100, &bump);
+    v.extend_from_slice(&[1, 2, 3]);
+    let used = bump.allocated_bytes();
+
+    v.shrink_to_fit();
+    assert_eq!(v, [1, 2, 3]);
+    assert_eq!(v.capacity(), 3);
+    assert_eq!(bump.allocated_bytes(), used - 97 * 4);
+
+    // The next allocation lands right below the shrunk buffer.
+    let next = bump.alloc(0u32) as *const u32;
+    assert_eq!(next, unsafe { v.as_ptr().sub(1) });
+}
+
+#[test]
+fn test_shrink_to_reclaims_arena_tail() {
+    let bump = Bump::new();
+    let mut v: Vec<u64> = Vec::with_capacity_in(10, &bump);
+    v.extend_from_slice(&[1, 2, 3, 4, 5, 6]);
+    let used = bump.allocated_bytes();
+
+    // Shrinking to more than the length keeps the requested slack.
+    v.shrink_to(8);
+    assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+    assert_eq!(v.capacity(), 8);
+    assert_eq!(bump.allocated_bytes(), used - 2 * 8);
+
+    // A lower bound above the capacity is a no-op.
+    v.shrink_to(20);
+    assert_eq!(v.capacity(), 8);
+
+    v.shrink_to(0);
+    assert_eq!(v.capacity(), 6);
+    assert_eq!(bump.allocated_bytes(), used - 4 * 8);
+}
+
+#[test]
+fn test_shrink_to_fit_not_last_allocation() {
+    let bump = Bump::new();
+    let mut v: Vec<u32> = Vec::with_capacity_in(100, &bump);
+    v.extend_from_slice(&[1, 2, 3]);
+    bump.alloc(0u8);
+    let used = bump.allocated_bytes();
+
+    v.shrink_to_fit();
+    assert_eq!(v, [1, 2, 3]);
+    assert_eq!(bump.allocated_bytes(), used);
+}
+
+#[test]
+fn test_mutable_iteration() {
+    let bump = Bump::new();
+    let mut v = vec![in &bump; 1, 2, 3];
+
+    for x in &mut v {
+        *x += 1;
+    }
+    assert_eq!(v, [2, 3, 4]);
+
+    v.iter_mut().rev().enumerate().for_each(|(i, x)| *x *= i);
+    assert_eq!(v, [4, 3, 0]);
+
+    let mut sum = 0;
+    for x in &v {
+        sum += x;
+    }
+    assert_eq!(sum, 7);
+}
+
+#[test]
+fn test_repeat_in() {
+    let bump = Bump::new();
+
+    for n in 0..20 {
+        let v = Vec::repeat_in(&[1u16, 2, 3], n, &bump);
+        let expected: std::vec::Vec<u16> = [1, 2, 3].repeat(n);
+        assert_eq!(v[..], expected[..]);
+        assert_eq!(v.capacity(), expected.len());
+    }
+
+    let empty: &[u64] = &[];
+    assert!(Vec::repeat_in(empty, 1000, &bump).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn test_repeat_in_overflow() {
+    let bump = Bump::new();
+    Vec::repeat_in(&[0u8; 2], usize::MAX / 2 + 1, &bump);
+}
+
+#[test]
+fn test_from_iter_in_exact_size_allocates_once() {
+    let bump = Bump::new();
+
+    let v = Vec::from_iter_in((0..100u32).map(|x| x * 2), &bump);
+    assert_eq!(v.len(), 100);
+    assert_eq!(v.capacity(), 100);
+    assert_eq!(bump.allocated_objects_count(), 1);
+    assert!(v.iter().enumerate().all(|(i, &x)| x == i as u32 * 2));
+
+    // Iterators without an exact size hint still work.
+    let v = Vec::from_iter_in((0..100u32).filter(|x| x % 3 == 0), &bump);
+    assert_eq!(v.len(), 34);
+}
+
+#[test]
+fn test_from_iter_in_wrong_size_hint() {
+    struct Liar(std::ops::Range<u8>, usize);
+
+    impl Iterator for Liar {
+        type Item = u8;
+        fn next(&mut self) -> Option<u8> {
+            self.0.next()
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.1, Some(self.1))
+        }
+    }
+
+    let bump = Bump::new();
+
+    let v = Vec::from_iter_in(Liar(0..10, 3), &bump);
+    assert_eq!(v, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+    let v = Vec::from_iter_in(Liar(0..3, 10), &bump);
+    assert_eq!(v, [0, 1, 2]);
+
+    let v = Vec::from_iter_in((0..5).map(|_| ()), &bump);
+    assert_eq!(v.len(), 5);
+}
+
+#[test]
+fn test_as_chunks() {
+    let bump = Bump::new();
+    let mut v: Vec<u32> = Vec::from_iter_in(0..19, &bump);
+
+    let (chunks, remainder) = v.as_chunks::<8>();
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[1], [8, 9, 10, 11, 12, 13, 14, 15]);
+    assert_eq!(remainder, [16, 17, 18]);
+
+    let (chunks, remainder) = v.as_chunks_mut::<8>();
+    for chunk in chunks {
+        for x in chunk.iter_mut() {
+            *x += 100;
+        }
+    }
+    for x in remainder {
+        *x = 0;
+    }
+    assert_eq!(v[7], 107);
+    assert_eq!(v[16..], [0, 0, 0]);
+
+    let empty: Vec<u32> = Vec::new_in(&bump);
+    let (chunks, remainder) = empty.as_chunks::<4>();
+    assert!(chunks.is_empty());
+    assert!(remainder.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn test_as_chunks_zero() {
+    let bump = Bump::new();
+    let v = vec![in &bump; 1, 2, 3];
+    let _ = v.as_chunks::<0>();
+}
+
+#[test]
+fn test_try_push() {
+    let bump = Bump::new();
+    let mut v = Vec::with_capacity_in(1, &bump);
+    assert_eq!(v.try_push(1), Ok(()));
+    assert_eq!(v.try_push(2), Ok(()));
+    assert_eq!(v, [1, 2]);
+
+    // A zero-sized element type can't grow past `usize::MAX` elements, so the
+    // value comes back and the vector is untouched.
+    let mut v: Vec<Cell<()>> = Vec::new_in(&bump);
+    unsafe { v.set_len(usize::MAX) };
+    assert!(v.try_push(Cell::new(())).is_err());
+    assert_eq!(v.len(), usize::MAX);
+    unsafe { v.set_len(0) };
+}
+
+#[test]
+fn test_extend_from_within() {
+    let bump = Bump::new();
+
+    // Decode a run of LZ-style back-references, each of which copies from
+    // earlier output and forces the buffer to grow along the way.
+    let mut out: Vec<u8> = Vec::with_capacity_in(1, &bump);
+    out.extend_from_slice(b"ab");
+    for _ in 0..6 {
+        let len = out.len();
+        out.extend_from_within(..len);
+    }
+    assert_eq!(out.len(), 128);
+    assert!(out.chunks(2).all(|pair| pair == b"ab"));
+
+    let mut v = vec![in &bump; String::from("x"), String::from("y")];
+    v.extend_from_within(1..=1);
+    v.extend_from_within(0..0);
+    assert_eq!(v, ["x", "y", "y"]);
+}
+
+#[test]
+#[should_panic]
+fn test_extend_from_within_out_of_bounds() {
+    let bump = Bump::new();
+    let mut v = vec![in &bump; 1, 2, 3];
+    v.extend_from_within(2..4);
+}
+
+#[test]
+#[should_panic(expected = "attempted to index slice up to maximum usize")]
+fn test_extend_from_within_inclusive_end_overflow() {
+    let bump = Bump::new();
+    let mut v = vec![in &bump; 1, 2, 3];
+    v.extend_from_within(..=usize::MAX);
+}
+
+#[test]
+#[should_panic(expected = "attempted to index slice from after maximum usize")]
+fn test_extend_from_within_exclusive_start_overflow() {
+    use std::ops::Bound::{Excluded, Unbounded};
+    let bump = Bump::new();
+    let mut v = vec![in &bump; 1, 2, 3];
+    v.extend_from_within((Excluded(usize::MAX), Unbounded));
+}
+
+#[test]
+fn test_extend_from_within_clone_panic() {
+    struct PanicOnThirdClone<'a>(&'a Cell<usize>);
+
+    impl Clone for PanicOnThirdClone<'_> {
+        fn clone(&self) -> Self {
+            self.0.set(self.0.get() + 1);
+            if self.0.get() == 3 {
+                panic!("third clone");
+            }
+            PanicOnThirdClone(self.0)
+        }
+    }
+
+    let bump = Bump::new();
+    let clones = Cell::new(0);
+    let mut v = Vec::new_in(&bump);
+    for _ in 0..4 {
+        v.push(PanicOnThirdClone(&clones));
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        v.extend_from_within(..);
+    }));
+    assert!(result.is_err());
+    assert_eq!(v.len(), 6);
+}
+
+#[test]
+fn test_push_grows_in_place_without_waste() {
+    let bump = Bump::with_capacity(1 << 20);
+    let mut v: Vec<u64> = Vec::new_in(&bump);
+    for i in 0..10_000 {
+        v.push(i);
+    }
+
+    // With no other allocations in between, every reallocation grew the
+    // buffer in place, so the arena holds nothing but the final buffer.
+    assert_eq!(bump.iter_allocated_chunks_raw().count(), 1);
+    assert_eq!(
+        bump.allocated_bytes(),
+        v.capacity() * std::mem::size_of::<u64>()
+    );
+}
+
+#[test]
+fn test_reserve_with_growth_factor() {
+    let bump = Bump::new();
+    let mut v = Vec::new_in(&bump);
+    let mut capacities = std::vec::Vec::new();
+    for i in 0..100u32 {
+        v.reserve_with_growth_factor(1, 3);
+        v.push(i);
+        if capacities.last() != Some(&v.capacity()) {
+            capacities.push(v.capacity());
+        }
+    }
+    assert_eq!(capacities, [1, 3, 9, 27, 81, 243]);
+
+    // Growth by the factor still gives way to larger requests.
+    let mut v: Vec<u8> = Vec::new_in(&bump);
+    v.push(0);
+    v.reserve_with_growth_factor(100, 2);
+    assert!(v.capacity() >= 101);
+}
+
+#[test]
+#[should_panic(expected = "growth factor must be at least 2")]
+fn test_reserve_with_growth_factor_too_small() {
+    let bump = Bump::new();
+    let mut v = Vec::<u8>::new_in(&bump);
+    v.reserve_with_growth_factor(1, 1);
+}
+
+#[test]
+fn test_slice_methods_in_place() {
+    let bump = Bump::new();
+    let mut v = vec![in &bump; 1, 2, 3, 4, 5, 6];
+    let buf = v.as_ptr();
+
+    v.rotate_left(2);
+    assert_eq!(v, [3, 4, 5, 6, 1, 2]);
+    v.rotate_right(4);
+    assert_eq!(v, [5, 6, 1, 2, 3, 4]);
+    v.reverse();
+    assert_eq!(v, [4, 3, 2, 1, 6, 5]);
+    v.swap(0, 5);
+    assert_eq!(v, [5, 3, 2, 1, 6, 4]);
+    v[1..4].fill(0);
+    assert_eq!(v, [5, 0, 0, 0, 6, 4]);
+
+    // All of these reorder the initialized elements within the same buffer.
+    assert_eq!(v.as_ptr(), buf);
+    assert_eq!(v.len(), 6);
+}
+
+#[test]
+fn test_to_std_vec() {
+    let bump = Bump::new();
+    let v = vec![in &bump; 1, 2, 3];
+    let owned: std::vec::Vec<i32> = v.to_std_vec();
+    assert_eq!(owned, [1, 2, 3]);
+    // The arena vector is untouched.
+    assert_eq!(v, [1, 2, 3]);
+
+    let empty: Vec<u8> = Vec::new_in(&bump);
+    assert!(empty.to_std_vec().is_empty());
+}
+
+#[test]
+fn test_into_std_vec_moves_elements() {
+    use std::rc::Rc;
+
+    let marker = Rc::new(());
+    let owned = {
+        let bump = Bump::new();
+        let mut v = Vec::with_capacity_in(10, &bump);
+        for _ in 0..3 {
+            v.push(Rc::clone(&marker));
+        }
+        let owned = v.into_std_vec();
+        assert_eq!(bump.allocated_bytes(), 0);
+        owned
+    };
+    // Nothing was cloned or dropped along the way, and the arena is gone.
+    assert_eq!(Rc::strong_count(&marker), 4);
+    assert_eq!(owned.len(), 3);
+    assert_eq!(owned.capacity(), 3);
+    drop(owned);
+    assert_eq!(Rc::strong_count(&marker), 1);
+}
+
+#[test]
+fn test_split_off_moves_elements() {
+    use std::rc::Rc;
+
+    let bump = Bump::new();
+    let items: std::vec::Vec<_> = (0..6).map(Rc::new).collect();
+    let mut v = Vec::new_in(&bump);
+    v.extend(items.iter().cloned());
+    let capacity = v.capacity();
+
+    let mut tail = v.split_off(4);
+    assert_eq!(v.len(), 4);
+    assert_eq!(v.capacity(), capacity);
+    assert_eq!(tail.len(), 2);
+    assert_eq!(*tail[0], 4);
+    // Moved, not cloned: each item is still held by `items` and one vector.
+    assert!(items.iter().all(|i| Rc::strong_count(i) == 2));
+
+    // The two halves have separate storage.
+    tail.push(Rc::new(6));
+    v.push(Rc::new(7));
+    assert_eq!(*v[4], 7);
+    assert_eq!(*tail[2], 6);
+
+    let empty = v.split_off(5);
+    assert!(empty.is_empty());
+    let all = v.split_off(0);
+    assert!(v.is_empty());
+    assert_eq!(all.len(), 5);
+}
+
+#[test]
+#[should_panic(expected = "`at` out of bounds")]
+fn test_split_off_out_of_bounds() {
+    let bump = Bump::new();
+    let mut v = vec![in &bump; 1, 2, 3];
+    v.split_off(4);
+}
+
+#[test]
+fn test_extend_from_slice_clone() {
+    let bump = Bump::new();
+    let mut v = vec![in &bump; String::from("a")];
+    let src = [String::from("b"), String::from("c"), String::from("d")];
+    v.extend_from_slice(&src);
+    assert_eq!(v, ["a", "b", "c", "d"]);
+    // The source is untouched.
+    assert_eq!(src, ["b", "c", "d"]);
+}
+
+#[test]
+fn test_extend_from_slice_clone_panic() {
+    struct PanicOnClone<'a>(u32, &'a Cell<usize>);
+
+    impl Clone for PanicOnClone<'_> {
+        fn clone(&self) -> Self {
+            if self.0 == 3 {
+                panic!("clone failed");
+            }
+            PanicOnClone(self.0, self.1)
+        }
+    }
+
+    impl Drop for PanicOnClone<'_> {
+        fn drop(&mut self) {
+            self.1.set(self.1.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    let bump = Bump::new();
+    let mut v = vec![in &bump; PanicOnClone(0, &drops)];
+    let src = [
+        PanicOnClone(1, &drops),
+        PanicOnClone(2, &drops),
+        PanicOnClone(3, &drops),
+        PanicOnClone(4, &drops),
+    ];
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        v.extend_from_slice(&src);
+    }));
+    assert!(result.is_err());
+
+    // Room for the whole slice was reserved up front, and the two elements
+    // cloned before the panic were dropped again.
+    assert!(v.capacity() >= 5);
+    assert_eq!(v.iter().map(|x| x.0).collect::<std::vec::Vec<_>>(), [0]);
+    assert_eq!(drops.get(), 2);
+}
+
+#[derive(Debug)]
+struct CountDrops<'a>(&'a Cell<usize>);
+
+impl Drop for CountDrops<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+fn count_drops_vec<'a, 'bump>(
+    bump: &'bump Bump,
+    drops: &'a Cell<usize>,
+    len: usize,
+) -> Vec<'bump, CountDrops<'a>> {
+    let mut v = Vec::with_capacity_in(len, bump);
+    for _ in 0..len {
+        v.push(CountDrops(drops));
+    }
+    v
+}
+
+#[test]
+fn test_truncate_and_clear_drop_elements() {
+    let drops = Cell::new(0);
+    let b = Bump::new();
+    let mut v = count_drops_vec(&b, &drops, 5);
+
+    v.truncate(2);
+    assert_eq!(drops.get(), 3);
+    assert_eq!(v.len(), 2);
+
+    v.clear();
+    assert_eq!(drops.get(), 5);
+    assert!(v.is_empty());
+}
+
+#[test]
+fn test_pop_returns_element_without_dropping() {
+    let drops = Cell::new(0);
+    let b = Bump::new();
+    let mut v = count_drops_vec(&b, &drops, 2);
+
+    let last = v.pop().unwrap();
+    assert_eq!(drops.get(), 0);
+    drop(last);
+    assert_eq!(drops.get(), 1);
+
+    // A popped element that is not kept is dropped right away.
+    v.pop();
+    assert_eq!(drops.get(), 2);
+    assert!(v.pop().is_none());
+}
+
+#[test]
+fn test_drop_vec_drops_elements() {
+    let drops = Cell::new(0);
+    let b = Bump::new();
+    let v = count_drops_vec(&b, &drops, 4);
+
+    drop(v);
+    assert_eq!(drops.get(), 4);
+}
+
+#[test]
+fn test_drop_into_iter_drops_remaining_elements() {
+    let drops = Cell::new(0);
+    let b = Bump::new();
+    let mut iter = count_drops_vec(&b, &drops, 4).into_iter();
+
+    drop(iter.next());
+    drop(iter.next_back());
+    assert_eq!(drops.get(), 2);
+
+    drop(iter);
+    assert_eq!(drops.get(), 4);
+}
+
+#[test]
+fn test_into_bump_slice_does_not_drop_elements() {
+    let drops = Cell::new(0);
+    let b = Bump::new();
+    let slice = count_drops_vec(&b, &drops, 3).into_bump_slice();
+    assert_eq!(slice.len(), 3);
+    assert_eq!(drops.get(), 0);
+}
+
+#[test]
+fn test_extract_if_partitions_in_one_pass() {
+    let b = Bump::new();
+    let mut queue = vec![in &b; 1, 2, 3, 4, 5, 6, 7];
+    let mut calls = 0;
+
+    let ready: std::vec::Vec<_> = queue
+        .extract_if(|x| {
+            calls += 1;
+            *x % 3 == 0
+        })
+        .collect();
+
+    assert_eq!(calls, 7);
+    assert_eq!(ready, [3, 6]);
+    assert_eq!(queue, [1, 2, 4, 5, 7]);
+}
+
+#[test]
+fn test_extract_if_can_modify_retained_elements() {
+    let b = Bump::new();
+    let mut v = vec![in &b; 1, 2, 3, 4];
+    let removed: std::vec::Vec<_> = v
+        .extract_if(|x| {
+            *x *= 10;
+            *x > 20
+        })
+        .collect();
+    assert_eq!(removed, [30, 40]);
+    assert_eq!(v, [10, 20]);
+}
+
+#[test]
+fn test_extract_if_dropped_early_retains_the_rest() {
+    let b = Bump::new();
+    let mut v = vec![in &b; 1, 2, 3, 4, 5, 6];
+    let mut visited = std::vec::Vec::new();
+
+    {
+        let mut iter = v.extract_if(|x| {
+            visited.push(*x);
+            *x % 2 == 0
+        });
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(4));
+    }
+
+    // Elements after the last one visited are kept without being tested.
+    assert_eq!(visited, [1, 2, 3, 4]);
+    assert_eq!(v, [1, 3, 5, 6]);
+
+    // Dropping it without iterating removes nothing.
+    v.extract_if(|_| true);
+    assert_eq!(v, [1, 3, 5, 6]);
+}
+
+#[test]
+fn test_extract_if_drops_only_extracted_elements() {
+    let drops = Cell::new(0);
+    let b = Bump::new();
+    let mut v = count_drops_vec(&b, &drops, 6);
+    let mut i = 0;
+
+    v.extract_if(|_| {
+        i += 1;
+        i % 2 == 0
+    })
+    .for_each(drop);
+
+    assert_eq!(drops.get(), 3);
+    assert_eq!(v.len(), 3);
+    drop(v);
+    assert_eq!(drops.get(), 6);
+}
+
+#[test]
+fn test_extract_if_predicate_panic_keeps_remaining_elements() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let b = Bump::new();
+    let mut v = vec![in &b; 1, 2, 3, 4, 5];
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        v.extract_if(|x| {
+            if *x == 4 {
+                panic!("predicate failed");
+            }
+            *x == 2
+        })
+        .for_each(drop);
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(v, [1, 3, 4, 5]);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_drain_filter_alias_names_extract_if() {
+    let b = Bump::new();
+    let mut v = vec![in &b; 1, 2, 3, 4];
+    let iter: bumpalo::collections::vec::DrainFilter<'_, '_, i32, _> =
+        v.extract_if(|x| *x % 2 == 0);
+    assert_eq!(iter.collect::<std::vec::Vec<_>>(), [2, 4]);
+    assert_eq!(v, [1, 3]);
+}
+
+#[test]
+fn test_element_accessors_via_deref() {
+    let b = Bump::new();
+    let mut v = vec![in &b; 1, 2, 3, 4, 5];
+
+    *v.first_mut().unwrap() = 10;
+    *v.last_mut().unwrap() = 50;
+    *v.get_mut(1).unwrap() = 20;
+    v.get_mut(2..4).unwrap().copy_from_slice(&[30, 40]);
+    assert_eq!(v, [10, 20, 30, 40, 50]);
+
+    assert_eq!(v.get(4), Some(&50));
+    assert_eq!(v.get(1..=2), Some(&[20, 30][..]));
+    assert_eq!(v.get(3..), Some(&[40, 50][..]));
+    assert_eq!(v.get(5), None);
+    assert_eq!(v.get(4..6), None);
+    assert!(v.get_mut(5).is_none());
+    assert!(v.get_mut(..6).is_none());
+
+    let (first, rest) = v.split_first_mut().unwrap();
+    *first += rest.len();
+    assert_eq!(v[0], 14);
+
+    let mut empty: Vec<u32> = Vec::new_in(&b);
+    assert_eq!(empty.first(), None);
+    assert_eq!(empty.last(), None);
+    assert!(empty.first_mut().is_none());
+    assert!(empty.last_mut().is_none());
+    assert_eq!(empty.get(..), Some(&[][..]));
+}
+
+#[test]
+fn test_partition_point() {
+    let b = Bump::new();
+    let v = vec![in &b; 1, 2, 3, 3, 5, 6, 7];
+    assert_eq!(v.partition_point(|&x| x < 0), 0);
+    assert_eq!(v.partition_point(|&x| x < 3), 2);
+    assert_eq!(v.partition_point(|&x| x <= 3), 4);
+    assert_eq!(v.partition_point(|&x| x < 100), 7);
+
+    let empty: Vec<i32> = Vec::new_in(&b);
+    assert_eq!(empty.partition_point(|_| true), 0);
+}